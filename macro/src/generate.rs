@@ -1,10 +1,13 @@
-use proc_macro2::{Ident, Punct, Spacing, Span, TokenStream};
+use std::cmp;
+use std::convert::TryFrom;
+
+use proc_macro2::{Ident, Literal, Punct, Spacing, Span, TokenStream};
 use quote::{quote, quote_spanned, ToTokens};
 use syn::Token;
 
 use num_bigint::BigInt;
 
-use crate::{BoundedInteger, Kind};
+use crate::{BoundedInteger, Comparisons, Kind, Repr, ReprSize, ReprSizeFixed};
 
 #[derive(Clone, Copy)]
 pub(crate) struct Features {
@@ -13,23 +16,34 @@ pub(crate) struct Features {
 }
 
 pub(crate) fn generate(item: &BoundedInteger, tokens: &mut TokenStream, features: Features) {
-    generate_item(item, tokens);
+    let mut item_tokens = TokenStream::new();
+    generate_item(item, &mut item_tokens);
+    generate_zerocopy_derives(item, item_tokens, tokens);
+    generate_layout_assertion(item, tokens);
     generate_impl(item, tokens);
 
-    // TODO: Implement FromStr, TryFrom and TryInto. This will require adding error types to the
-    // main crate.
+    // TODO: Implement TryFrom and TryInto for the primitives.
     generate_ops_traits(item, tokens);
     generate_cmp_traits(item, tokens);
+    generate_types_eq(item, tokens);
+    generate_hash(item, tokens);
     generate_as_ref_borrow(item, tokens);
+    generate_char_conversions(item, tokens);
+    generate_nonzero_conversions(item, tokens);
     generate_default(item, tokens);
     generate_iter_traits(item, tokens, features);
     generate_fmt_traits(item, tokens);
+    generate_from_str(item, tokens);
     generate_to_primitive_traits(item, tokens);
+    generate_bounded_integer_trait(item, tokens);
+    generate_to_string(item, tokens);
+    generate_from_bytes(item, tokens);
     if features.serde {
         generate_serde(item, tokens);
     }
 
-    if cfg!(feature = "generate_tests") {
+    // The tests are generated from the bounds, which aren't known for a range given as a constant.
+    if cfg!(feature = "generate_tests") && item.const_range.is_none() {
         generate_tests(item, tokens);
     }
 }
@@ -40,22 +54,36 @@ fn generate_item(item: &BoundedInteger, tokens: &mut TokenStream) {
     for attr in &item.attrs {
         attr.to_tokens(tokens);
     }
+    // A zero-sized struct has no field to show its value, and an offset struct's field is not its
+    // value, so they implement `Debug` manually.
+    let debug = (!has_manual_debug(item)).then(|| quote!(::core::fmt::Debug,));
+    let copy = (!item.no_copy).then(|| quote!(::core::marker::Copy,));
+    // A pattern type doesn't implement the comparison traits, so they are implemented manually.
+    let eq = (item.comparisons >= Comparisons::Eq && item.niche.is_none())
+        .then(|| quote!(::core::cmp::PartialEq, ::core::cmp::Eq,));
+    let ord = (item.comparisons >= Comparisons::Ord && item.niche.is_none())
+        .then(|| quote!(::core::cmp::PartialOrd, ::core::cmp::Ord,));
     tokens.extend(quote! {
         #[derive(
-            ::core::fmt::Debug,
-            ::core::hash::Hash,
+            #debug
             ::core::clone::Clone,
-            ::core::marker::Copy,
-            ::core::cmp::PartialEq,
-            ::core::cmp::Eq,
-            ::core::cmp::PartialOrd,
-            ::core::cmp::Ord
+            #copy
+            #eq
+            #ord
         )]
     });
 
-    tokens.extend(match &item.kind {
-        Kind::Enum(_) => quote!(#[repr(#repr)]),
-        Kind::Struct(_) => quote!(#[repr(transparent)]),
+    let align = item.align.as_ref().map(|align| quote!(, align(#align)));
+    tokens.extend(match (&item.kind, &align) {
+        // A single-variant enum without a repr is zero-sized.
+        (Kind::Enum(_), _) if item.zst => TokenStream::new(),
+        (Kind::Enum(_), _) if item.c_int => quote!(#[repr(C #align)]),
+        // A fieldless enum with a primitive `repr` already has the layout of that primitive in C,
+        // and Rust rejects combining it with `C`.
+        (Kind::Enum(_), _) => quote!(#[repr(#repr #align)]),
+        (Kind::Struct(_), None) if item.repr_c => quote!(#[repr(C)]),
+        (Kind::Struct(_), None) => quote!(#[repr(transparent)]),
+        (Kind::Struct(_), Some(align)) => quote!(#[repr(C #align)]),
     });
 
     item.vis.to_tokens(tokens);
@@ -68,14 +96,28 @@ fn generate_item(item: &BoundedInteger, tokens: &mut TokenStream) {
     item.ident.to_tokens(tokens);
 
     match &item.kind {
+        Kind::Struct(_) if item.zst => {
+            tokens.extend(quote_spanned!(item.brace_token.span=> (());));
+        }
         Kind::Struct(_) => {
-            tokens.extend(quote_spanned!(item.brace_token.span=> (::core::primitive::#repr);));
+            let field_type = struct_field_type(item);
+            tokens.extend(quote_spanned!(item.brace_token.span=> (#field_type);));
+        }
+        Kind::Enum(_) if item.zst => {
+            let variant = enum_variant(item.range.start());
+            tokens.extend(quote_spanned!(item.brace_token.span=> { #variant }));
         }
         Kind::Enum(_) => {
             let mut inner_tokens = TokenStream::new();
 
             let first_variant = enum_variant(item.range.start());
-            let start_literal = item.repr.number_literal(item.range.start());
+            // The discriminants of a `#[repr(C)]` enum are written as `isize`.
+            let discriminant = if item.c_int {
+                &Repr::new(true, ReprSize::Pointer)
+            } else {
+                &item.repr
+            };
+            let start_literal = discriminant.number_literal(item.range.start());
             inner_tokens.extend(quote!(#first_variant = #start_literal));
 
             let mut variant = item.range.start() + 1;
@@ -90,17 +132,115 @@ fn generate_item(item: &BoundedInteger, tokens: &mut TokenStream) {
     }
 }
 
+/// Wraps the item in a macro that adds zerocopy derives to it when the `zerocopy` feature is
+/// enabled.
+fn generate_zerocopy_derives(
+    item: &BoundedInteger,
+    item_tokens: TokenStream,
+    tokens: &mut TokenStream,
+) {
+    let crate_path = &item.crate_path;
+
+    // Alignment adds padding, and single-value enums have no `repr` to read the value from.
+    let traits = match item.kind {
+        _ if item.align.is_some() => None,
+        Kind::Enum(_) if item.zst => None,
+        // The bytes of an offset would be mistaken for the value.
+        Kind::Struct(_) if item.offset_storage.is_some() => None,
+        // The pattern type of a niche struct's field doesn't implement the traits.
+        Kind::Struct(_) if item.niche.is_some() => None,
+        // The derived `TryFromBytes` would accept any value of the primitive, so only enums
+        // implement it.
+        Kind::Struct(_) => Some(quote!(IntoBytes, Immutable, KnownLayout)),
+        Kind::Enum(_) => Some(quote!(TryFromBytes, IntoBytes, Immutable, KnownLayout)),
+    };
+
+    tokens.extend(match traits {
+        Some(traits) => quote! {
+            #crate_path::__private::derive_zerocopy! {
+                [#traits]
+                #item_tokens
+            }
+        },
+        None => item_tokens,
+    });
+}
+
+fn generate_layout_assertion(item: &BoundedInteger, tokens: &mut TokenStream) {
+    let ident = &item.ident;
+    let repr = &item.repr;
+
+    if item.niche.is_some() {
+        let crate_path = &item.crate_path;
+        tokens.extend(quote! {
+            #crate_path::__private::require_niche!();
+            const _: () = ::core::assert!(
+                ::core::mem::size_of::<::core::option::Option<#ident>>()
+                    == ::core::mem::size_of::<#ident>()
+            );
+        });
+    }
+
+    // The macro can't see the bounds of a range given as a constant, so they are checked here.
+    if item.const_range.is_some() {
+        tokens.extend(quote! {
+            const _: () = ::core::assert!(
+                #ident::MIN_VALUE <= #ident::MAX_VALUE,
+                "The start of the range must not be after the end",
+            );
+        });
+    }
+
+    if item.align.is_some() {
+        return;
+    }
+
+    let storage = item.offset_storage.as_ref().unwrap_or(repr);
+    let size = if item.zst {
+        quote!(0)
+    } else {
+        quote!(::core::mem::size_of::<::core::primitive::#storage>())
+    };
+
+    tokens.extend(quote! {
+        const _: () = ::core::assert!(::core::mem::size_of::<#ident>() == #size);
+    });
+}
+
 fn generate_impl(item: &BoundedInteger, tokens: &mut TokenStream) {
     let ident = &item.ident;
 
     let mut content = TokenStream::new();
     generate_min_max_value(item, &mut content);
     generate_min_max(item, &mut content);
+    generate_value_consts(item, &mut content);
+    generate_variants(item, &mut content);
     generate_unchecked_constructors(item, &mut content);
+    generate_range_checks(item, &mut content);
     generate_checked_constructors(item, &mut content);
+    generate_fallback_constructor(item, &mut content);
+    generate_array_constructor(item, &mut content);
+    generate_slice_conversions(item, &mut content);
+    generate_const_constructor(item, &mut content);
+    generate_ascii_constructor(item, &mut content);
+    generate_float_constructors(item, &mut content);
     generate_getters(item, &mut content);
+    generate_casts(item, &mut content);
+    generate_bool_conversions(item, &mut content);
+    generate_offsets(item, &mut content);
+    generate_signed_diff(item, &mut content);
+    generate_bit_packing(item, &mut content);
     generate_inherent_operators(item, &mut content);
+    generate_unsigned_operators(item, &mut content);
+    generate_rotations(item, &mut content);
     generate_checked_operators(item, &mut content);
+    generate_bounded_operators(item, &mut content);
+    generate_saturating_step(item, &mut content);
+    generate_widening_mul(item, &mut content);
+    generate_rounding_division(item, &mut content);
+    generate_mul_div(item, &mut content);
+    generate_min_max_with(item, &mut content);
+    generate_try_sum(item, &mut content);
 
     tokens.extend(quote! {
         impl #ident {
@@ -113,6 +253,19 @@ fn generate_min_max_value(item: &BoundedInteger, tokens: &mut TokenStream) {
     let repr = &item.repr;
     let vis = &item.vis;
 
+    let range = quote! {
+        /// The range of values that this bounded integer can contain. As it implements
+        /// [`RangeBounds`](::core::ops::RangeBounds), it can be passed to APIs expecting a range.
+        #vis const RANGE: ::core::ops::RangeInclusive<::core::primitive::#repr> =
+            Self::MIN_VALUE..=Self::MAX_VALUE;
+    };
+
+    // The bounds of a range given as a constant are declared beside the module instead.
+    if item.const_range.is_some() {
+        tokens.extend(range);
+        return;
+    }
+
     let min_value_doc = format!(
         "The smallest value that this bounded integer can contain; {}.",
         item.range.start()
@@ -130,23 +283,42 @@ fn generate_min_max_value(item: &BoundedInteger, tokens: &mut TokenStream) {
         #vis const MIN_VALUE: ::core::primitive::#repr = #min_value;
         #[doc = #max_value_doc]
         #vis const MAX_VALUE: ::core::primitive::#repr = #max_value;
+        #range
     });
 }
 
 fn generate_min_max(item: &BoundedInteger, tokens: &mut TokenStream) {
+    let repr = &item.repr;
     let vis = &item.vis;
 
-    let min_doc = format!(
-        "The smallest value of the bounded integer; {}.",
-        item.range.start()
-    );
-    let max_doc = format!(
-        "The largest value of the bounded integer; {}.",
-        item.range.end()
-    );
+    let (min_doc, max_doc) = if item.const_range.is_some() {
+        (
+            "The smallest value of the bounded integer.".to_owned(),
+            "The largest value of the bounded integer.".to_owned(),
+        )
+    } else {
+        (
+            format!(
+                "The smallest value of the bounded integer; {}.",
+                item.range.start()
+            ),
+            format!(
+                "The largest value of the bounded integer; {}.",
+                item.range.end()
+            ),
+        )
+    };
 
     let (min, max) = match &item.kind {
-        Kind::Struct(_) => (quote!(Self(Self::MIN_VALUE)), quote!(Self(Self::MAX_VALUE))),
+        Kind::Struct(_) if item.zst => (quote!(Self(())), quote!(Self(()))),
+        Kind::Struct(_) if item.const_range.is_some() => (
+            struct_from_value(item, &quote!(Self::MIN_VALUE)),
+            struct_from_value(item, &quote!(Self::MAX_VALUE)),
+        ),
+        Kind::Struct(_) => (
+            struct_from_literal(item, item.range.start()),
+            struct_from_literal(item, item.range.end()),
+        ),
         Kind::Enum(_) => {
             let (min, max) = (
                 enum_variant(item.range.start()),
@@ -157,11 +329,180 @@ fn generate_min_max(item: &BoundedInteger, tokens: &mut TokenStream) {
         }
     };
 
+    let size_bits = if item.const_range.is_some() {
+        let unsigned = Repr::new(false, item.repr.size);
+        quote! {
+            ::core::primitive::#unsigned::BITS
+                - (Self::MAX_VALUE.wrapping_sub(Self::MIN_VALUE) as ::core::primitive::#unsigned)
+                    .leading_zeros()
+        }
+    } else {
+        Literal::u64_unsuffixed((item.range.end() - item.range.start()).bits()).into_token_stream()
+    };
+
     tokens.extend(quote! {
         #[doc = #min_doc]
         #vis const MIN: Self = #min;
         #[doc = #max_doc]
         #vis const MAX: Self = #max;
+
+        /// The number of bits needed to distinguish every value in the range, for packing bounded
+        /// integers into bitfields. This is the number of bits in the largest
+        /// [`to_index`](Self::to_index), not the width of the underlying integer.
+        #vis const SIZE_BITS: ::core::primitive::u32 = #size_bits;
+
+        /// The number of bits in the underlying integer, unlike [`SIZE_BITS`](Self::SIZE_BITS)
+        /// which only counts the bits needed by the range.
+        #vis const REPR_BITS: ::core::primitive::u32 = ::core::primitive::#repr::BITS;
+    });
+}
+
+fn generate_value_consts(item: &BoundedInteger, tokens: &mut TokenStream) {
+    if !item.value_consts {
+        return;
+    }
+
+    let vis = &item.vis;
+
+    let mut value = item.range.start().clone();
+    while value <= *item.range.end() {
+        let name = match value.sign() {
+            num_bigint::Sign::Minus => format!("VN{}", value.magnitude()),
+            _ => format!("V{value}"),
+        };
+        let name = Ident::new(&name, Span::call_site());
+        let doc = format!("The value {value}.");
+        let constant = match item.kind {
+            Kind::Struct(_) if item.zst => quote!(Self(())),
+            Kind::Struct(_) => struct_from_literal(item, &value),
+            Kind::Enum(_) => {
+                let variant = enum_variant(&value);
+                quote!(Self::#variant)
+            }
+        };
+        tokens.extend(quote! {
+            #[doc = #doc]
+            #vis const #name: Self = #constant;
+        });
+        value += 1;
+    }
+}
+
+fn generate_variants(item: &BoundedInteger, tokens: &mut TokenStream) {
+    if let Kind::Struct(_) = item.kind {
+        if item.enum_facade {
+            generate_facade_variants(item, tokens);
+        }
+        return;
+    }
+
+    let vis = &item.vis;
+
+    let mut variants = TokenStream::new();
+    let mut name_arms = TokenStream::new();
+    let mut len = 0_usize;
+    let mut variant = item.range.start().clone();
+    while variant <= *item.range.end() {
+        let name = enum_variant(&variant);
+        let name_str = name.to_string();
+        variants.extend(quote!(Self::#name,));
+        name_arms.extend(quote!(Self::#name => #name_str,));
+        len += 1;
+        variant += 1;
+    }
+    let len = Literal::usize_unsuffixed(len);
+
+    tokens.extend(quote! {
+        /// Every variant of the enum, in ascending order.
+        #vis const VARIANTS: [Self; #len] = [#variants];
+    });
+    generate_variant_iterators(item, tokens, &len);
+    tokens.extend(quote! {
+        /// Returns the name of the enum variant, such as `P5` or `N3`.
+        #[must_use]
+        #vis const fn variant_name(self) -> &'static ::core::primitive::str {
+            match self { #name_arms }
+        }
+    });
+}
+
+/// Generates the associated items of an enum for a large enum generated as a struct: a constant
+/// in place of each variant, and the variant list and names built without matching on each value.
+fn generate_facade_variants(item: &BoundedInteger, tokens: &mut TokenStream) {
+    let repr = &item.repr;
+    let vis = &item.vis;
+
+    let mut names = TokenStream::new();
+    let mut len = 0_usize;
+    let mut value = item.range.start().clone();
+    while value <= *item.range.end() {
+        let name = enum_variant(&value);
+        let name_str = name.to_string();
+        let doc = format!("The value {value}.");
+        let constant = struct_from_literal(item, &value);
+        tokens.extend(quote! {
+            #[doc = #doc]
+            #vis const #name: Self = #constant;
+        });
+        names.extend(quote!(#name_str,));
+        len += 1;
+        value += 1;
+    }
+    let len = Literal::usize_unsuffixed(len);
+
+    let variant = struct_from_value(
+        item,
+        &quote!(Self::MIN_VALUE.wrapping_add(i as ::core::primitive::#repr)),
+    );
+    tokens.extend(quote! {
+        /// Every variant of the enum, in ascending order.
+        #vis const VARIANTS: [Self; #len] = {
+            let mut variants = [Self::MIN; #len];
+            let mut i = 0;
+            while i < #len {
+                variants[i] = #variant;
+                i += 1;
+            }
+            variants
+        };
+    });
+    generate_variant_iterators(item, tokens, &len);
+    let unsigned = Repr::new(false, item.repr.size);
+    // A type that isn't `Copy` is only borrowed, like in `get`.
+    let receiver = if item.no_copy {
+        quote!(&self)
+    } else {
+        quote!(self)
+    };
+    tokens.extend(quote! {
+        /// Returns the name of the enum variant, such as `P5` or `N3`.
+        #[must_use]
+        #vis const fn variant_name(#receiver) -> &'static ::core::primitive::str {
+            const NAMES: [&::core::primitive::str; #len] = [#names];
+            let index = self.get().wrapping_sub(Self::MIN_VALUE) as ::core::primitive::#unsigned;
+            NAMES[index as ::core::primitive::usize]
+        }
+    });
+}
+
+fn generate_variant_iterators(item: &BoundedInteger, tokens: &mut TokenStream, len: &Literal) {
+    let vis = &item.vis;
+
+    tokens.extend(quote! {
+        /// Returns every variant of the enum sorted by ascending value. Enums always cover a
+        /// contiguous range, so this is currently the same array as
+        /// [`VARIANTS`](Self::VARIANTS); use it when the code relies on the ordering rather than
+        /// on declaration order.
+        #[must_use]
+        #vis const fn sorted_variants() -> [Self; #len] {
+            Self::VARIANTS
+        }
+
+        /// Iterates over the variants of the enum in ascending order. Unlike iterating over a
+        /// range of primitives, this only yields values that are variants of the enum.
+        #vis fn variants() -> impl ::core::iter::ExactSizeIterator<Item = Self> {
+            ::core::iter::IntoIterator::into_iter(Self::VARIANTS)
+        }
     });
 }
 
@@ -170,7 +511,22 @@ fn generate_unchecked_constructors(item: &BoundedInteger, tokens: &mut TokenStre
     let vis = &item.vis;
 
     let (new_unchecked_const, new_unchecked_body) = match item.kind {
-        Kind::Struct(_) => (Some(Token![const](Span::call_site())), quote!(Self(n))),
+        Kind::Struct(_) if item.zst => (Some(Token![const](Span::call_site())), quote!(Self(()))),
+        Kind::Struct(_) => (
+            Some(Token![const](Span::call_site())),
+            struct_from_value(item, &quote!(n)),
+        ),
+        // An aligned or zero-sized enum has a different size to its repr, so it cannot be
+        // transmuted from it.
+        Kind::Enum(_) if !has_repr_layout(item) => (
+            None,
+            quote! {
+                match Self::new(n) {
+                    ::core::option::Option::Some(val) => val,
+                    ::core::option::Option::None => ::core::hint::unreachable_unchecked(),
+                }
+            },
+        ),
         Kind::Enum(_) => (
             None,
             quote!(::core::mem::transmute::<::core::primitive::#repr, Self>(n)),
@@ -191,6 +547,23 @@ The value must not be outside the valid range of values; it must not be less tha
         #vis #new_unchecked_const unsafe fn new_unchecked(n: ::core::primitive::#repr) -> Self {
             #new_unchecked_body
         }
+    });
+
+    if !has_repr_layout(item) {
+        return;
+    }
+
+    tokens.extend(quote! {
+        /// Creates a bounded integer from a primitive received over FFI, without checking the
+        /// value.
+        ///
+        /// The bounded integer has the same size and alignment as its underlying primitive, so
+        /// it can be passed directly to and from `extern "C"` functions.
+        #[doc = #safety_doc]
+        #[must_use]
+        #vis #new_unchecked_const unsafe fn from_repr_unchecked(n: ::core::primitive::#repr) -> Self {
+            Self::new_unchecked(n)
+        }
 
         /// Creates a shared reference to a bounded integer from a shared reference to a primitive.
         #[doc = #safety_doc]
@@ -211,29 +584,46 @@ The value must not be outside the valid range of values; it must not be less tha
     });
 }
 
+fn generate_range_checks(item: &BoundedInteger, tokens: &mut TokenStream) {
+    let repr = &item.repr;
+    let vis = &item.vis;
+
+    tokens.extend(quote! {
+        /// Checks whether the given value is in the range of the bounded integer.
+        #[must_use]
+        #[inline]
+        #vis const fn in_range(n: ::core::primitive::#repr) -> ::core::primitive::bool {
+            n >= Self::MIN_VALUE && n <= Self::MAX_VALUE
+        }
+
+        /// Clamps the given value to the range of the bounded integer, without creating a bounded
+        /// integer.
+        #[must_use]
+        #[inline]
+        #vis const fn clamp_repr(n: ::core::primitive::#repr) -> ::core::primitive::#repr {
+            if n < Self::MIN_VALUE {
+                Self::MIN_VALUE
+            } else if n > Self::MAX_VALUE {
+                Self::MAX_VALUE
+            } else {
+                n
+            }
+        }
+    });
+}
+
 fn generate_checked_constructors(item: &BoundedInteger, tokens: &mut TokenStream) {
     let repr = &item.repr;
     let vis = &item.vis;
 
+    // Only structs use this, as enums match on every variant.
+    let in_range_value = if item.zst {
+        quote!(Self(()))
+    } else {
+        struct_from_value(item, &quote!(n))
+    };
+
     let (new_body, new_saturating_body) = match item.kind {
-        Kind::Struct(_) => (
-            quote! {
-                if Self::in_range(n) {
-                    ::core::option::Option::Some(Self(n))
-                } else {
-                    ::core::option::Option::None
-                }
-            },
-            quote! {
-                if n < Self::MIN_VALUE {
-                    Self::MIN
-                } else if n > Self::MAX_VALUE {
-                    Self::MAX
-                } else {
-                    Self(n)
-                }
-            },
-        ),
         Kind::Enum(_) => {
             let mut new_arms = TokenStream::new();
             let mut new_saturating_arms = quote! {
@@ -265,16 +655,32 @@ fn generate_checked_constructors(item: &BoundedInteger, tokens: &mut TokenStream
                 quote! { match n { #new_saturating_arms } },
             )
         }
+        // When every value of the repr is in range, there is nothing to check.
+        Kind::Struct(_) if is_full_range(item) => (
+            quote!(::core::option::Option::Some(#in_range_value)),
+            in_range_value,
+        ),
+        Kind::Struct(_) => (
+            quote! {
+                if Self::in_range(n) {
+                    ::core::option::Option::Some(#in_range_value)
+                } else {
+                    ::core::option::Option::None
+                }
+            },
+            quote! {
+                if n < Self::MIN_VALUE {
+                    Self::MIN
+                } else if n > Self::MAX_VALUE {
+                    Self::MAX
+                } else {
+                    #in_range_value
+                }
+            },
+        ),
     };
 
     tokens.extend(quote! {
-        /// Checks whether the given value is in the range of the bounded integer.
-        #[must_use]
-        #[inline]
-        #vis const fn in_range(n: ::core::primitive::#repr) -> ::core::primitive::bool {
-            n >= Self::MIN_VALUE && n <= Self::MAX_VALUE
-        }
-
         /// Creates a bounded integer if the given value is within the range
         /// [[`MIN`](Self::MIN), [`MAX`](Self::MAX)].
         #[must_use]
@@ -283,182 +689,1220 @@ fn generate_checked_constructors(item: &BoundedInteger, tokens: &mut TokenStream
             #new_body
         }
 
+        /// Creates a bounded integer by setting the value to [`MIN`](Self::MIN) or
+        /// [`MAX`](Self::MAX) if it is too low or too high respectively.
+        #[must_use]
+        #[inline]
+        #vis const fn new_saturating(n: ::core::primitive::#repr) -> Self {
+            #new_saturating_body
+        }
+    });
+
+    if matches!(item.kind, Kind::Enum(_)) || item.enum_facade {
+        tokens.extend(quote! {
+            /// Creates the variant whose value is nearest to the given value.
+            ///
+            /// Values in the range map to their own variant and values beyond it to the nearest
+            /// end. As the variants are contiguous this is the same as
+            /// [`new_saturating`](Self::new_saturating), but unlike it, it keeps picking the
+            /// nearest variant should the enum ever have gaps between its variants.
+            #[must_use]
+            #[inline]
+            #vis const fn new_nearest(value: ::core::primitive::#repr) -> Self {
+                Self::new_saturating(value)
+            }
+        });
+    }
+
+    if !has_repr_layout(item) {
+        return;
+    }
+
+    tokens.extend(quote! {
         /// Creates a reference to a bounded integer from a reference to a primitive if the
         /// given value is within the range [[`MIN`](Self::MIN), [`MAX`](Self::MAX)].
         #[must_use]
         #[inline]
-        #vis fn new_ref(n: &::core::primitive::#repr) -> ::core::option::Option<&Self> {
-            Self::in_range(*n).then(|| {
-                // SAFETY: We just asserted that the value is in range.
-                unsafe { Self::new_ref_unchecked(n) }
-            })
+        #vis fn new_ref(n: &::core::primitive::#repr) -> ::core::option::Option<&Self> {
+            Self::in_range(*n).then(|| {
+                // SAFETY: We just asserted that the value is in range.
+                unsafe { Self::new_ref_unchecked(n) }
+            })
+        }
+
+        /// Creates a mutable reference to a bounded integer from a mutable reference to a
+        /// primitive if the given value is within the range
+        /// [[`MIN`](Self::MIN), [`MAX`](Self::MAX)].
+        #[must_use]
+        #[inline]
+        #vis fn new_mut(n: &mut ::core::primitive::#repr) -> ::core::option::Option<&mut Self> {
+            Self::in_range(*n).then(move || {
+                // SAFETY: We just asserted that the value is in range.
+                unsafe { Self::new_mut_unchecked(n) }
+            })
+        }
+    });
+}
+
+fn generate_slice_conversions(item: &BoundedInteger, tokens: &mut TokenStream) {
+    let repr = &item.repr;
+    let vis = &item.vis;
+
+    tokens.extend(quote! {
+        /// Checks that every value of the slice is within the range
+        /// [[`MIN`](Self::MIN), [`MAX`](Self::MAX)].
+        ///
+        /// # Errors
+        ///
+        /// Fails with the index and value of the first element that is out of range.
+        #[inline]
+        #vis const fn validate_slice(
+            s: &[::core::primitive::#repr],
+        ) -> ::core::result::Result<(), (::core::primitive::usize, ::core::primitive::#repr)> {
+            let mut i = 0;
+            while i < s.len() {
+                if !Self::in_range(s[i]) {
+                    return ::core::result::Result::Err((i, s[i]));
+                }
+                i += 1;
+            }
+            ::core::result::Result::Ok(())
+        }
+    });
+
+    // Reinterpreting the slice requires the type to have the layout of its repr.
+    if !has_repr_layout(item) {
+        return;
+    }
+
+    tokens.extend(quote! {
+        /// Creates a slice of bounded integers from a slice of primitives, without copying it.
+        ///
+        /// # Errors
+        ///
+        /// Fails with the index and value of the first element that is out of range.
+        #[inline]
+        #vis fn from_slice(
+            s: &[::core::primitive::#repr],
+        ) -> ::core::result::Result<&[Self], (::core::primitive::usize, ::core::primitive::#repr)> {
+            Self::validate_slice(s)?;
+            // SAFETY: We just checked that every value is in range.
+            ::core::result::Result::Ok(unsafe { Self::from_slice_unchecked(s) })
+        }
+
+        /// Creates a slice of bounded integers from a slice of primitives.
+        ///
+        /// # Safety
+        ///
+        /// Every value must be within the range; none may be less than
+        /// [`MIN_VALUE`](Self::MIN_VALUE) or greater than [`MAX_VALUE`](Self::MAX_VALUE).
+        #[must_use]
+        #vis unsafe fn from_slice_unchecked(s: &[::core::primitive::#repr]) -> &[Self] {
+            ::core::debug_assert!(::core::result::Result::is_ok(&Self::validate_slice(s)));
+            &*(s as *const [::core::primitive::#repr] as *const [Self])
+        }
+    });
+}
+
+fn generate_fallback_constructor(item: &BoundedInteger, tokens: &mut TokenStream) {
+    let repr = &item.repr;
+    let vis = &item.vis;
+
+    tokens.extend(quote! {
+        /// Creates a bounded integer if the given value is within the range
+        /// [[`MIN`](Self::MIN), [`MAX`](Self::MAX)], falling back to `default` otherwise.
+        #[must_use]
+        #[inline]
+        #vis const fn new_or(value: ::core::primitive::#repr, default: Self) -> Self {
+            match Self::new(value) {
+                ::core::option::Option::Some(bounded) => bounded,
+                ::core::option::Option::None => default,
+            }
+        }
+    });
+}
+
+fn generate_array_constructor(item: &BoundedInteger, tokens: &mut TokenStream) {
+    let repr = &item.repr;
+    let vis = &item.vis;
+
+    tokens.extend(quote! {
+        /// Creates an array of bounded integers if every value of the given array is within the
+        /// range [[`MIN`](Self::MIN), [`MAX`](Self::MAX)], returning [`None`] if any is not.
+        #[must_use]
+        #[inline]
+        #vis fn new_array<const N: ::core::primitive::usize>(
+            values: [::core::primitive::#repr; N],
+        ) -> ::core::option::Option<[Self; N]> {
+            for &n in &values {
+                if !Self::in_range(n) {
+                    return ::core::option::Option::None;
+                }
+            }
+            // SAFETY: We just checked that every value is in range.
+            ::core::option::Option::Some(values.map(|n| unsafe { Self::new_unchecked(n) }))
+        }
+    });
+}
+
+fn generate_const_constructor(item: &BoundedInteger, tokens: &mut TokenStream) {
+    let repr = &item.repr;
+    let vis = &item.vis;
+
+    let new_const_body = match item.kind {
+        Kind::Struct(_) if item.zst => quote!(Self(())),
+        Kind::Struct(_) => struct_from_value(item, &quote!(N)),
+        Kind::Enum(_) => quote! {
+            match Self::new(N) {
+                ::core::option::Option::Some(val) => val,
+                ::core::option::Option::None => ::core::unreachable!(),
+            }
+        },
+    };
+
+    tokens.extend(quote! {
+        /// Creates a bounded integer from a constant, failing to compile if the value is outside
+        /// the range [[`MIN`](Self::MIN), [`MAX`](Self::MAX)].
+        #[must_use]
+        #[inline]
+        #vis const fn new_const<const N: ::core::primitive::#repr>() -> Self {
+            const { ::core::assert!(Self::in_range(N), "constant out of range") };
+            #new_const_body
+        }
+    });
+}
+
+fn generate_ascii_constructor(item: &BoundedInteger, tokens: &mut TokenStream) {
+    let repr = &item.repr;
+    let vis = &item.vis;
+    let crate_path = &item.crate_path;
+
+    let sign_magnitude = |value| {
+        if item.repr.signed {
+            quote!((#value < 0, #value.unsigned_abs() as ::core::primitive::u128))
+        } else {
+            quote!((false, #value as ::core::primitive::u128))
+        }
+    };
+    let min = sign_magnitude(quote!(Self::MIN_VALUE));
+    let max = sign_magnitude(quote!(Self::MAX_VALUE));
+
+    tokens.extend(quote! {
+        /// Parses a bounded integer from an optionally signed ASCII decimal byte slice, without
+        /// requiring it to be valid UTF-8.
+        ///
+        /// # Errors
+        ///
+        /// Fails if the bytes are not a decimal integer or it is out of range.
+        #vis fn from_ascii(
+            bytes: &[::core::primitive::u8],
+        ) -> ::core::result::Result<Self, #crate_path::ParseError> {
+            let (negative, magnitude) = #crate_path::__private::from_ascii(bytes, #min, #max)?;
+            let n = if negative {
+                (magnitude as ::core::primitive::#repr).wrapping_neg()
+            } else {
+                magnitude as ::core::primitive::#repr
+            };
+            // SAFETY: The value was checked to be within the range.
+            ::core::result::Result::Ok(unsafe { Self::new_unchecked(n) })
+        }
+    });
+}
+
+fn generate_float_constructors(item: &BoundedInteger, tokens: &mut TokenStream) {
+    let repr = &item.repr;
+    let vis = &item.vis;
+
+    tokens.extend(quote! {
+        /// Creates a bounded integer from an `f32`. Returns [`None`] if the float is NaN,
+        /// infinite, has a fractional part or is out of range.
+        ///
+        /// An `f32` has only 24 bits of precision, so for wide ranges not every integer can be
+        /// given exactly: the literal `16_777_217.0` is already rounded to `16_777_216.0` before
+        /// it is passed in.
+        #[must_use]
+        #[inline]
+        #vis const fn checked_from_f32(
+            f: ::core::primitive::f32,
+        ) -> ::core::option::Option<Self> {
+            // Casting saturates, so a float past the end of the primitive would otherwise
+            // round-trip through its maximum. Since the maximum is odd, this is exactly one more
+            // than it.
+            let limit = ((::core::primitive::#repr::MAX >> 1) + 1) as ::core::primitive::f32 * 2.0;
+            let n = f as ::core::primitive::#repr;
+            let round_trip = n as ::core::primitive::f32;
+            if f.is_nan() || round_trip < f || round_trip > f || f >= limit {
+                return ::core::option::Option::None;
+            }
+            Self::new(n)
+        }
+
+        /// Creates a bounded integer from an `f32`, truncating it towards zero and saturating it
+        /// to the range. NaN is treated as zero.
+        ///
+        /// As with [`checked_from_f32`](Self::checked_from_f32), large values may have already
+        /// lost precision.
+        #[must_use]
+        #[inline]
+        #vis const fn from_f32_saturating(f: ::core::primitive::f32) -> Self {
+            Self::new_saturating(f as ::core::primitive::#repr)
+        }
+
+        /// Converts the value to an `f32`, rounding it to the nearest representable float.
+        ///
+        /// An `f32` has only 24 bits of precision, so this is only exact for values up to
+        /// 2<sup>24</sup> in magnitude.
+        #[must_use]
+        #[inline]
+        #vis const fn to_f32(self) -> ::core::primitive::f32 {
+            self.get() as ::core::primitive::f32
+        }
+
+        /// Converts the value to an `f64`, rounding it to the nearest representable float.
+        ///
+        /// An `f64` has only 53 bits of precision, so this is only exact for values up to
+        /// 2<sup>53</sup> in magnitude.
+        #[must_use]
+        #[inline]
+        #vis const fn to_f64(self) -> ::core::primitive::f64 {
+            self.get() as ::core::primitive::f64
+        }
+    });
+}
+
+fn generate_getters(item: &BoundedInteger, tokens: &mut TokenStream) {
+    let repr = &item.repr;
+    let vis = &item.vis;
+
+    let get_body = match item.kind {
+        _ if item.zst => quote!(Self::MIN_VALUE),
+        Kind::Struct(_) if item.offset_storage.is_some() => {
+            let stored = struct_stored_value(item);
+            quote!(Self::MIN_VALUE.wrapping_add(#stored as ::core::primitive::#repr))
+        }
+        Kind::Struct(_) => struct_stored_value(item),
+        Kind::Enum(_) if item.no_copy => quote!(*self.get_ref()),
+        Kind::Enum(_) => quote!(self as _),
+    };
+    // A type that isn't `Copy` is only borrowed to read its value.
+    let receiver = if item.no_copy {
+        quote!(&self)
+    } else {
+        quote!(self)
+    };
+
+    let ffi_doc = has_repr_layout(item).then(|| {
+        quote! {
+            ///
+            /// This is the FFI-safe inverse of [`from_repr_unchecked`](Self::from_repr_unchecked).
+        }
+    });
+
+    tokens.extend(quote! {
+        /// Returns the value of the bounded integer as a primitive type.
+        #ffi_doc
+        #[must_use]
+        #[inline]
+        #vis const fn get(#receiver) -> ::core::primitive::#repr {
+            #get_body
+        }
+    });
+
+    let crate_path = &item.crate_path;
+    tokens.extend(quote! {
+        /// Mixes the value into a 64-bit seed for deterministic hashing or sharding. The seed is the
+        /// same on every run and platform, and depends only on the value.
+        #[must_use]
+        #[inline]
+        #vis const fn hash_seed(#receiver) -> ::core::primitive::u64 {
+            #crate_path::__private::hash_seed(self.get() as ::core::primitive::u128)
+        }
+    });
+
+    let get_ref_body = match item.kind {
+        _ if item.zst => quote!(&Self::MIN_VALUE),
+        Kind::Struct(_) if item.niche.is_some() => {
+            let field_type = struct_field_type(item);
+            quote!(unsafe { &*(&self.0 as *const #field_type as *const ::core::primitive::#repr) })
+        }
+        Kind::Struct(_) => quote!(&self.0),
+        Kind::Enum(_) => {
+            quote!(unsafe { &*(self as *const Self as *const ::core::primitive::#repr) })
+        }
+    };
+
+    // An offset struct doesn't store its value, so it cannot lend out a reference to it.
+    if item.offset_storage.is_none() {
+        tokens.extend(quote! {
+            /// Returns a shared reference to the value of the bounded integer.
+            #[must_use]
+            #[inline]
+            #vis const fn get_ref(&self) -> &::core::primitive::#repr {
+                #get_ref_body
+            }
+
+            /// Returns a shared reference to the value of the bounded integer, like
+            /// [`get_ref`](Self::get_ref).
+            ///
+            /// # Safety
+            ///
+            /// There are no requirements beyond those of the safe `get_ref`: the reference cannot
+            /// be used to change the value, so it is always in range. This exists for code that
+            /// mirrors `get_mut` and wants both borrows to look alike; prefer `get_ref` everywhere
+            /// else.
+            #[must_use]
+            #[inline]
+            #vis const unsafe fn get_unchecked_ref(&self) -> &::core::primitive::#repr {
+                self.get_ref()
+            }
+        });
+    }
+
+    tokens.extend(quote! {
+        /// Compares the value of the bounded integer to a primitive. Unlike [`Ord`], this can be
+        /// used in const contexts.
+        #[must_use]
+        #[inline]
+        #vis const fn cmp_value(
+            #receiver,
+            v: ::core::primitive::#repr,
+        ) -> ::core::cmp::Ordering {
+            let value = self.get();
+            if value < v {
+                ::core::cmp::Ordering::Less
+            } else if value > v {
+                ::core::cmp::Ordering::Greater
+            } else {
+                ::core::cmp::Ordering::Equal
+            }
+        }
+    });
+
+    // A zero-sized or offset type doesn't store its value, so the value can't be mutated through
+    // it.
+    if item.zst || item.offset_storage.is_some() {
+        return;
+    }
+
+    tokens.extend(quote! {
+        /// Returns a mutable reference to the value of the bounded integer.
+        ///
+        /// # Safety
+        ///
+        /// This value must never be set to a value beyond the range of the bounded integer.
+        #[must_use]
+        #[inline]
+        #vis unsafe fn get_mut(&mut self) -> &mut ::core::primitive::#repr {
+            &mut *(self as *mut Self as *mut ::core::primitive::#repr)
+        }
+    });
+}
+
+fn generate_casts(item: &BoundedInteger, tokens: &mut TokenStream) {
+    let vis = &item.vis;
+
+    for signed in [false, true] {
+        let sizes = ReprSize::Fixed(ReprSizeFixed::Fixed8)
+            .larger_reprs()
+            .chain([ReprSize::Pointer]);
+        for size in sizes {
+            let target = Repr::new(signed, size);
+
+            // Pointer-sized integers are only guaranteed to be at least 16 bits wide.
+            let guaranteed = match size {
+                ReprSize::Fixed(_) => Repr::new(signed, size),
+                ReprSize::Pointer => Repr::new(signed, ReprSize::Fixed(ReprSizeFixed::Fixed16)),
+            };
+            let fits = guaranteed.minimum().unwrap() <= *item.range.start()
+                && *item.range.end() <= guaranteed.maximum().unwrap();
+            if !fits {
+                continue;
+            }
+
+            let name = Ident::new(&format!("as_{}", target.name), Span::call_site());
+            let doc = format!(
+                "Converts the bounded integer to a [`{}`], which can hold every value in its range.",
+                target.name,
+            );
+
+            tokens.extend(quote! {
+                #[doc = #doc]
+                #[must_use]
+                #[inline]
+                #vis const fn #name(self) -> ::core::primitive::#target {
+                    self.get() as ::core::primitive::#target
+                }
+            });
+        }
+    }
+}
+
+fn generate_bool_conversions(item: &BoundedInteger, tokens: &mut TokenStream) {
+    let vis = &item.vis;
+
+    // Only a range of two values maps one-to-one onto `bool`.
+    if item.range.end() - item.range.start() != BigInt::from(1) {
+        return;
+    }
+
+    let receiver = if item.no_copy {
+        quote!(&self)
+    } else {
+        quote!(self)
+    };
+
+    tokens.extend(quote! {
+        /// Converts a `bool` to the bounded integer, mapping `false` to [`MIN`](Self::MIN) and
+        /// `true` to [`MAX`](Self::MAX).
+        #[must_use]
+        #[inline]
+        #vis const fn from_bool(b: ::core::primitive::bool) -> Self {
+            if b {
+                Self::MAX
+            } else {
+                Self::MIN
+            }
+        }
+
+        /// Converts the bounded integer to a `bool`, mapping [`MIN`](Self::MIN) to `false` and
+        /// [`MAX`](Self::MAX) to `true`.
+        #[must_use]
+        #[inline]
+        #vis const fn to_bool(#receiver) -> ::core::primitive::bool {
+            self.get() == Self::MAX_VALUE
+        }
+    });
+}
+
+fn generate_offsets(item: &BoundedInteger, tokens: &mut TokenStream) {
+    let repr = &item.repr;
+    let vis = &item.vis;
+    let unsigned = Repr::new(false, item.repr.size);
+
+    tokens.extend(quote! {
+        /// Returns the distance forward from `self` to `other`, wrapping around from
+        /// [`MAX`](Self::MAX) to [`MIN`](Self::MIN) if `other` is less than `self`.
+        #[must_use]
+        #[inline]
+        #vis const fn wrapping_distance(self, other: Self) -> ::core::primitive::#unsigned {
+            if self.get() <= other.get() {
+                other.get().wrapping_sub(self.get()) as ::core::primitive::#unsigned
+            } else {
+                // Split the distance at the wrapping point so that it cannot overflow.
+                Self::MAX_VALUE.wrapping_sub(self.get()) as ::core::primitive::#unsigned
+                    + other.get().wrapping_sub(Self::MIN_VALUE) as ::core::primitive::#unsigned
+                    + 1
+            }
+        }
+
+        /// The number of values in the range, for declaring an array of type
+        /// `[T; Self::INDEX_LEN]` that [`to_index`](Self::to_index) can always index into.
+        ///
+        /// Using this constant fails to compile if the number doesn't fit in a [`usize`].
+        #vis const INDEX_LEN: ::core::primitive::usize = {
+            let max_index = Self::MAX_VALUE.wrapping_sub(Self::MIN_VALUE)
+                as ::core::primitive::#unsigned as ::core::primitive::u128;
+            ::core::assert!(
+                max_index < ::core::primitive::usize::MAX as ::core::primitive::u128,
+                "the range has too many values to index an array",
+            );
+            max_index as ::core::primitive::usize + 1
+        };
+
+        /// Returns the zero-based offset of the value from [`MIN`](Self::MIN), for indexing into
+        /// an array that has one element for each value in the range. It is always less than
+        /// [`INDEX_LEN`](Self::INDEX_LEN).
+        ///
+        /// The offset is truncated if it does not fit in a [`usize`].
+        #[must_use]
+        #[inline]
+        #vis const fn to_index(self) -> ::core::primitive::usize {
+            self.get().wrapping_sub(Self::MIN_VALUE) as ::core::primitive::#unsigned
+                as ::core::primitive::usize
+        }
+
+        /// Creates a bounded integer from its zero-based offset from [`MIN`](Self::MIN), the
+        /// inverse of [`to_index`](Self::to_index). Returns [`None`] if the index is beyond the
+        /// range.
+        #[must_use]
+        #[inline]
+        #vis const fn from_index(index: ::core::primitive::usize) -> ::core::option::Option<Self> {
+            let max_index = Self::MAX_VALUE.wrapping_sub(Self::MIN_VALUE) as ::core::primitive::#unsigned;
+            if index as ::core::primitive::u128 > max_index as ::core::primitive::u128 {
+                return ::core::option::Option::None;
+            }
+            Self::new(Self::MIN_VALUE.wrapping_add(index as ::core::primitive::#repr))
+        }
+
+        /// Returns the position of the value in the range as a float from `0.0` at
+        /// [`MIN`](Self::MIN) to `1.0` at [`MAX`](Self::MAX). If the range has only one value,
+        /// this is always `0.0`.
+        #[must_use]
+        #[inline]
+        #vis const fn normalized(self) -> ::core::primitive::f64 {
+            let span = Self::MAX_VALUE.wrapping_sub(Self::MIN_VALUE)
+                as ::core::primitive::#unsigned;
+            if span == 0 {
+                return 0.0;
+            }
+            let offset = self.get().wrapping_sub(Self::MIN_VALUE) as ::core::primitive::#unsigned;
+            offset as ::core::primitive::f64 / span as ::core::primitive::f64
+        }
+
+        /// Creates a bounded integer from its position in the range, the inverse of
+        /// [`normalized`](Self::normalized). `0.0` gives [`MIN`](Self::MIN), `1.0` gives
+        /// [`MAX`](Self::MAX), and values in between are rounded to the nearest integer. Returns
+        /// [`None`] if `t` is NaN or outside `0.0..=1.0`.
+        #[must_use]
+        #[inline]
+        #vis const fn from_normalized(t: ::core::primitive::f64) -> ::core::option::Option<Self> {
+            if t.is_nan() || t < 0.0 || t > 1.0 {
+                return ::core::option::Option::None;
+            }
+            let span = Self::MAX_VALUE.wrapping_sub(Self::MIN_VALUE)
+                as ::core::primitive::#unsigned;
+            // Converting a wide span to a float can round it up, so clamp the offset back into
+            // the range.
+            let mut offset =
+                (t * span as ::core::primitive::f64 + 0.5) as ::core::primitive::#unsigned;
+            if offset > span {
+                offset = span;
+            }
+            Self::new(Self::MIN_VALUE.wrapping_add(offset as ::core::primitive::#repr))
+        }
+    });
+}
+
+fn generate_signed_diff(item: &BoundedInteger, tokens: &mut TokenStream) {
+    let vis = &item.vis;
+
+    // The difference can be anywhere in `-width..=width`; the extra value below keeps the
+    // primitive signed even when the width is zero.
+    let width = item.range.end() - item.range.start();
+    let Some(diff) = Repr::smallest_repr(&(-&width - 1), &width) else {
+        return;
+    };
+
+    tokens.extend(quote! {
+        /// Returns `self - rhs` as a primitive wide enough to hold the difference between any two
+        /// values of the bounded integer, in either direction. Unlike the subtraction operator,
+        /// this never goes out of range.
+        #[must_use]
+        #[inline]
+        #vis const fn signed_diff(self, rhs: Self) -> ::core::primitive::#diff {
+            // Truncating both values preserves their difference, since it fits in the result.
+            let lhs = self.get() as ::core::primitive::#diff;
+            let rhs = rhs.get() as ::core::primitive::#diff;
+            lhs.wrapping_sub(rhs)
+        }
+    });
+}
+
+fn generate_bit_packing(item: &BoundedInteger, tokens: &mut TokenStream) {
+    let repr = &item.repr;
+    let vis = &item.vis;
+    let unsigned = Repr::new(false, item.repr.size);
+
+    let check_field = quote! {
+        ::core::assert!(
+            shift <= 64 && Self::SIZE_BITS <= 64 - shift,
+            "bit field does not fit in the word",
+        );
+    };
+
+    tokens.extend(quote! {
+        /// Stores the value's [`to_index`](Self::to_index) in the [`SIZE_BITS`](Self::SIZE_BITS)
+        /// bits of `word` starting at bit `shift`, leaving the other bits unchanged.
+        ///
+        /// # Panics
+        ///
+        /// Panics if the bit field does not fit in a `u64`.
+        #[must_use]
+        #[inline]
+        #vis const fn pack_into(
+            self,
+            word: ::core::primitive::u64,
+            shift: ::core::primitive::u32,
+        ) -> ::core::primitive::u64 {
+            #check_field
+            if Self::SIZE_BITS == 0 {
+                return word;
+            }
+            let mask = ::core::primitive::u64::MAX >> (64 - Self::SIZE_BITS);
+            let offset = self.get().wrapping_sub(Self::MIN_VALUE) as ::core::primitive::#unsigned;
+            (word & !(mask << shift)) | ((offset as ::core::primitive::u64) << shift)
+        }
+
+        /// Reads a value stored by [`pack_into`](Self::pack_into) from the
+        /// [`SIZE_BITS`](Self::SIZE_BITS) bits of `word` starting at bit `shift`. Returns [`None`]
+        /// if the bits don't hold a value in the range.
+        ///
+        /// # Panics
+        ///
+        /// Panics if the bit field does not fit in a `u64`.
+        #[must_use]
+        #[inline]
+        #vis const fn unpack_from(
+            word: ::core::primitive::u64,
+            shift: ::core::primitive::u32,
+        ) -> ::core::option::Option<Self> {
+            #check_field
+            if Self::SIZE_BITS == 0 {
+                return ::core::option::Option::Some(Self::MIN);
+            }
+            let mask = ::core::primitive::u64::MAX >> (64 - Self::SIZE_BITS);
+            let offset = (word >> shift) & mask;
+            let max_offset = Self::MAX_VALUE.wrapping_sub(Self::MIN_VALUE) as ::core::primitive::#unsigned;
+            if offset as ::core::primitive::u128 > max_offset as ::core::primitive::u128 {
+                return ::core::option::Option::None;
+            }
+            Self::new(Self::MIN_VALUE.wrapping_add(offset as ::core::primitive::#repr))
+        }
+    });
+}
+
+fn generate_inherent_operators(item: &BoundedInteger, tokens: &mut TokenStream) {
+    let vis = &item.vis;
+    let repr = &item.repr;
+
+    if item.repr.signed {
+        tokens.extend(quote! {
+            /// Computes the absolute value of `self`, panicking if it is out of range.
+            #[must_use]
+            #[inline]
+            #vis fn abs(self) -> Self {
+                Self::new(self.get().abs()).expect("Absolute value out of range")
+            }
+
+            /// Returns a number representing the sign of `self`: `-1` if it is negative, `0` if it
+            /// is zero and `1` if it is positive.
+            #[must_use]
+            #[inline]
+            #vis const fn signum(self) -> ::core::primitive::#repr {
+                self.get().signum()
+            }
+        });
+    }
+
+    tokens.extend(quote! {
+        /// Returns `true` if the value is even.
+        #[must_use]
+        #[inline]
+        #vis const fn is_even(self) -> ::core::primitive::bool {
+            self.get() % 2 == 0
+        }
+
+        /// Returns `true` if the value is odd.
+        #[must_use]
+        #[inline]
+        #vis const fn is_odd(self) -> ::core::primitive::bool {
+            !self.is_even()
+        }
+
+        /// Raises `self` to the power of `exp`, using exponentiation by squaring. Panics if it
+        /// is out of range.
+        #[must_use]
+        #[inline]
+        #vis fn pow(self, exp: ::core::primitive::u32) -> Self {
+            Self::new(self.get().pow(exp)).expect("Value raised to power out of range")
+        }
+        /// Calculates the quotient of Euclidean division of `self` by `rhs`. Panics if `rhs`
+        /// is 0 or the result is out of range.
+        #[must_use]
+        #[inline]
+        #vis fn div_euclid(self, rhs: ::core::primitive::#repr) -> Self {
+            Self::new(self.get().div_euclid(rhs)).expect("Attempted to divide out of range")
+        }
+        /// Calculates the least nonnegative remainder of `self (mod rhs)`. Panics if `rhs` is 0
+        /// or the result is out of range.
+        #[must_use]
+        #[inline]
+        #vis fn rem_euclid(self, rhs: ::core::primitive::#repr) -> Self {
+            Self::new(self.get().rem_euclid(rhs))
+                .expect("Attempted to divide with remainder out of range")
+        }
+    });
+
+    if item.range.start().sign() == num_bigint::Sign::Plus {
+        tokens.extend(quote! {
+            /// Calculates the least nonnegative remainder of `value (mod modulus)`, which is always
+            /// less than `modulus` and so at most `MAX - 1`.
+            #[must_use]
+            #[inline]
+            #vis const fn reduce(
+                value: ::core::primitive::#repr,
+                modulus: Self,
+            ) -> ::core::primitive::#repr {
+                value.rem_euclid(modulus.get())
+            }
+        });
+    }
+
+    tokens.extend(quote! {
+        /// Returns the logarithm of `self` with respect to an arbitrary base, rounded down.
+        /// Returns [`None`] if `self` is not positive or `base` is less than 2.
+        #[must_use]
+        #[inline]
+        #vis const fn checked_ilog(
+            self,
+            base: ::core::primitive::#repr,
+        ) -> ::core::option::Option<::core::primitive::u32> {
+            self.get().checked_ilog(base)
+        }
+
+        /// Returns the base 2 logarithm of `self`, rounded down. Returns [`None`] if `self` is not
+        /// positive.
+        #[must_use]
+        #[inline]
+        #vis const fn checked_ilog2(self) -> ::core::option::Option<::core::primitive::u32> {
+            self.get().checked_ilog2()
+        }
+
+        /// Returns the base 10 logarithm of `self`, rounded down. Returns [`None`] if `self` is not
+        /// positive.
+        #[must_use]
+        #[inline]
+        #vis const fn checked_ilog10(self) -> ::core::option::Option<::core::primitive::u32> {
+            self.get().checked_ilog10()
+        }
+    });
+}
+
+fn generate_unsigned_operators(item: &BoundedInteger, tokens: &mut TokenStream) {
+    if item.repr.signed {
+        return;
+    }
+
+    let vis = &item.vis;
+
+    tokens.extend(quote! {
+        /// Returns the smallest power of two greater than or equal to `self`. Returns [`None`] if
+        /// it overflows the underlying integer or is out of range.
+        #[must_use]
+        #[inline]
+        #vis const fn checked_next_power_of_two(self) -> ::core::option::Option<Self> {
+            match self.get().checked_next_power_of_two() {
+                ::core::option::Option::Some(val) => Self::new(val),
+                ::core::option::Option::None => ::core::option::Option::None,
+            }
+        }
+    });
+
+    tokens.extend(quote! {
+        /// Returns the integer square root of `self`, rounded down. Returns [`None`] if it is
+        /// below the range, which can only happen when [`MIN`](Self::MIN) is above 1.
+        #[must_use]
+        #[inline]
+        #vis const fn checked_isqrt(self) -> ::core::option::Option<Self> {
+            Self::new(self.get().isqrt())
+        }
+    });
+
+    // The root is never greater than the value, and every root is at least the minimum if the
+    // minimum is 0 or 1, so then the root is always in range.
+    if *item.range.start() <= BigInt::from(1) && item.const_range.is_none() {
+        tokens.extend(quote! {
+            /// Returns the integer square root of `self`, rounded down. The root is never
+            /// greater than the value, and the range starts at 0 or 1, so it is always in range.
+            #[must_use]
+            #[inline]
+            #vis const fn isqrt(self) -> Self {
+                match Self::new(self.get().isqrt()) {
+                    ::core::option::Option::Some(root) => root,
+                    ::core::option::Option::None => ::core::unreachable!(),
+                }
+            }
+        });
+    }
+}
+
+fn generate_rotations(item: &BoundedInteger, tokens: &mut TokenStream) {
+    let vis = &item.vis;
+
+    for (name, direction) in [("rotate_left", "left"), ("rotate_right", "right")] {
+        let ident = Ident::new(name, Span::call_site());
+        let doc = format!(
+            "Rotates the bits of the underlying integer {direction} by `n` bits. Returns \
+            [`None`] if the result is out of range."
+        );
+
+        tokens.extend(quote! {
+            #[doc = #doc]
+            #[must_use]
+            #[inline]
+            #vis const fn #ident(self, n: ::core::primitive::u32) -> ::core::option::Option<Self> {
+                Self::new(self.get().#ident(n))
+            }
+        });
+    }
+}
+
+fn generate_checked_operators(item: &BoundedInteger, tokens: &mut TokenStream) {
+    let vis = &item.vis;
+
+    for op in CHECKED_OPERATORS {
+        if !item.repr.signed && op.variants == OpVariants::Signed {
+            continue;
+        }
+
+        let rhs = match op.rhs {
+            Some("Self") => Some({
+                let repr = &item.repr;
+                quote!(::core::primitive::#repr)
+            }),
+            Some(name) => Some({
+                let ident = Ident::new(name, Span::call_site());
+                quote!(::core::primitive::#ident)
+            }),
+            None => None,
+        };
+        let rhs_type = rhs.as_ref().map(|ty| quote!(rhs: #ty,));
+        let rhs_value = rhs.map(|_| quote!(rhs,));
+
+        let checked_name = Ident::new(&format!("checked_{}", op.name), Span::call_site());
+        let checked_comment = format!("Checked {}.", op.description.trim());
+
+        tokens.extend(quote! {
+            #[doc = #checked_comment]
+            #[must_use]
+            #[inline]
+            #vis const fn #checked_name(self, #rhs_type) -> ::core::option::Option<Self> {
+                match self.get().#checked_name(#rhs_value) {
+                    ::core::option::Option::Some(val) => Self::new(val),
+                    ::core::option::Option::None => ::core::option::Option::None,
+                }
+            }
+        });
+
+        if op.variants == OpVariants::NoSaturating
+            || !item.repr.signed && op.variants == OpVariants::SignedSaturating
+        {
+            continue;
+        }
+
+        let saturating_name = Ident::new(&format!("saturating_{}", op.name), Span::call_site());
+        let saturating_comment = format!("Saturating {}.", op.description.trim());
+
+        tokens.extend(quote! {
+            #[doc = #saturating_comment]
+            #[must_use]
+            #[inline]
+            #vis const fn #saturating_name(self, #rhs_type) -> Self {
+                Self::new_saturating(self.get().#saturating_name(#rhs_value))
+            }
+        });
+    }
+
+    tokens.extend(quote! {
+        /// Checked bitwise not.
+        #[must_use]
+        #[inline]
+        #vis const fn checked_not(self) -> ::core::option::Option<Self> {
+            Self::new(!self.get())
+        }
+
+        /// Checked increment by one.
+        #[must_use]
+        #[inline]
+        #vis const fn checked_increment(self) -> ::core::option::Option<Self> {
+            self.checked_add(1)
+        }
+
+        /// Checked decrement by one.
+        #[must_use]
+        #[inline]
+        #vis const fn checked_decrement(self) -> ::core::option::Option<Self> {
+            self.checked_sub(1)
+        }
+
+        /// Advances to the next value, wrapping from [`MAX`](Self::MAX) back to
+        /// [`MIN`](Self::MIN). This is useful for cycling through states.
+        #[must_use]
+        #[inline]
+        #vis const fn cycle_next(self) -> Self {
+            match self.checked_increment() {
+                ::core::option::Option::Some(next) => next,
+                ::core::option::Option::None => Self::MIN,
+            }
+        }
+
+        /// Goes back to the previous value, wrapping from [`MIN`](Self::MIN) back to
+        /// [`MAX`](Self::MAX).
+        #[must_use]
+        #[inline]
+        #vis const fn cycle_prev(self) -> Self {
+            match self.checked_decrement() {
+                ::core::option::Option::Some(prev) => prev,
+                ::core::option::Option::None => Self::MAX,
+            }
+        }
+    });
+}
+
+fn generate_bounded_operators(item: &BoundedInteger, tokens: &mut TokenStream) {
+    let vis = &item.vis;
+
+    tokens.extend(quote! {
+        /// Checked addition of another value of the same bounded integer type.
+        #[must_use]
+        #[inline]
+        #vis const fn checked_add_bounded(self, rhs: Self) -> ::core::option::Option<Self> {
+            self.checked_add(rhs.get())
+        }
+
+        /// Checked subtraction of another value of the same bounded integer type.
+        #[must_use]
+        #[inline]
+        #vis const fn checked_sub_bounded(self, rhs: Self) -> ::core::option::Option<Self> {
+            self.checked_sub(rhs.get())
+        }
+
+        /// Checked multiplication by another value of the same bounded integer type.
+        #[must_use]
+        #[inline]
+        #vis const fn checked_mul_bounded(self, rhs: Self) -> ::core::option::Option<Self> {
+            self.checked_mul(rhs.get())
         }
 
-        /// Creates a mutable reference to a bounded integer from a mutable reference to a
-        /// primitive if the given value is within the range
-        /// [[`MIN`](Self::MIN), [`MAX`](Self::MAX)].
+        /// Checked division by another value of the same bounded integer type. Returns [`None`]
+        /// if `rhs` is zero or the quotient is out of range.
         #[must_use]
         #[inline]
-        #vis fn new_mut(n: &mut ::core::primitive::#repr) -> ::core::option::Option<&mut Self> {
-            Self::in_range(*n).then(move || {
-                // SAFETY: We just asserted that the value is in range.
-                unsafe { Self::new_mut_unchecked(n) }
-            })
+        #vis const fn checked_div_bounded(self, rhs: Self) -> ::core::option::Option<Self> {
+            self.checked_div(rhs.get())
         }
 
-        /// Creates a bounded integer by setting the value to [`MIN`](Self::MIN) or
-        /// [`MAX`](Self::MAX) if it is too low or too high respectively.
+        /// Checked remainder by another value of the same bounded integer type. Returns [`None`]
+        /// if `rhs` is zero or the remainder is out of range.
         #[must_use]
         #[inline]
-        #vis const fn new_saturating(n: ::core::primitive::#repr) -> Self {
-            #new_saturating_body
+        #vis const fn checked_rem_bounded(self, rhs: Self) -> ::core::option::Option<Self> {
+            self.checked_rem(rhs.get())
         }
     });
 }
 
-fn generate_getters(item: &BoundedInteger, tokens: &mut TokenStream) {
-    let repr = &item.repr;
+fn generate_widening_mul(item: &BoundedInteger, tokens: &mut TokenStream) {
     let vis = &item.vis;
 
-    let get_body = match item.kind {
-        Kind::Struct(_) => quote!(self.0),
-        Kind::Enum(_) => quote!(self as _),
+    // Pointer-sized integers are at most 64 bits wide.
+    let wide = match item.repr.size {
+        ReprSize::Fixed(ReprSizeFixed::Fixed8) => ReprSizeFixed::Fixed16,
+        ReprSize::Fixed(ReprSizeFixed::Fixed16) => ReprSizeFixed::Fixed32,
+        ReprSize::Fixed(ReprSizeFixed::Fixed32) => ReprSizeFixed::Fixed64,
+        ReprSize::Fixed(ReprSizeFixed::Fixed64) | ReprSize::Pointer => ReprSizeFixed::Fixed128,
+        // There is no wider primitive to hold the product.
+        ReprSize::Fixed(ReprSizeFixed::Fixed128) => return,
     };
+    let wide = Repr::new(item.repr.signed, ReprSize::Fixed(wide));
 
     tokens.extend(quote! {
-        /// Returns the value of the bounded integer as a primitive type.
+        /// Multiplies by another value of the same bounded integer type, returning the exact
+        /// product in a primitive twice as wide, which can never overflow.
         #[must_use]
         #[inline]
-        #vis const fn get(self) -> ::core::primitive::#repr {
-            #get_body
+        #vis const fn widening_mul(self, rhs: Self) -> ::core::primitive::#wide {
+            self.get() as ::core::primitive::#wide * rhs.get() as ::core::primitive::#wide
         }
     });
+}
 
-    let (get_ref_const, get_ref_body) = match item.kind {
-        Kind::Struct(_) => (Some(Token![const](Span::call_site())), quote!(&self.0)),
-        Kind::Enum(_) => (
-            None,
-            quote!(unsafe { &*(self as *const Self as *const ::core::primitive::#repr) }),
-        ),
+fn generate_saturating_step(item: &BoundedInteger, tokens: &mut TokenStream) {
+    let vis = &item.vis;
+    let signed = Repr::new(true, item.repr.size);
+    let add = if item.repr.signed {
+        quote!(saturating_add)
+    } else {
+        quote!(saturating_add_signed)
     };
 
     tokens.extend(quote! {
-        /// Returns a shared reference to the value of the bounded integer.
+        /// Moves the value by a signed step, saturating at [`MIN`](Self::MIN) and
+        /// [`MAX`](Self::MAX). This suits sliders and clamped counters.
         #[must_use]
         #[inline]
-        #vis #get_ref_const fn get_ref(&self) -> &::core::primitive::#repr {
-            #get_ref_body
+        #vis const fn saturating_step(self, n: ::core::primitive::#signed) -> Self {
+            Self::new_saturating(self.get().#add(n))
         }
+    });
+}
 
-        /// Returns a mutable reference to the value of the bounded integer.
-        ///
-        /// # Safety
+fn generate_mul_div(item: &BoundedInteger, tokens: &mut TokenStream) {
+    let repr = &item.repr;
+    let vis = &item.vis;
+
+    let body = if let ReprSize::Fixed(ReprSizeFixed::Fixed128) = repr.size {
+        quote! {
+            match self.get().checked_mul(mul) {
+                ::core::option::Option::Some(product) => match product.checked_div(div) {
+                    ::core::option::Option::Some(n) => Self::new(n),
+                    ::core::option::Option::None => ::core::option::Option::None,
+                },
+                ::core::option::Option::None => ::core::option::Option::None,
+            }
+        }
+    } else {
+        let wide = Repr::new(repr.signed, ReprSize::Fixed(ReprSizeFixed::Fixed128));
+        quote! {
+            if div == 0 {
+                return ::core::option::Option::None;
+            }
+            let n = self.get() as ::core::primitive::#wide * mul as ::core::primitive::#wide
+                / div as ::core::primitive::#wide;
+            if n < ::core::primitive::#repr::MIN as ::core::primitive::#wide
+                || n > ::core::primitive::#repr::MAX as ::core::primitive::#wide
+            {
+                return ::core::option::Option::None;
+            }
+            Self::new(n as ::core::primitive::#repr)
+        }
+    };
+
+    tokens.extend(quote! {
+        /// Computes `self * mul / div`, rounding towards zero. Returns [`None`] if `div` is zero or
+        /// the result is out of range.
         ///
-        /// This value must never be set to a value beyond the range of the bounded integer.
+        /// The product is computed in a 128-bit integer, so it cannot overflow unless the type
+        /// itself is 128 bits wide.
         #[must_use]
         #[inline]
-        #vis unsafe fn get_mut(&mut self) -> &mut ::core::primitive::#repr {
-            &mut *(self as *mut Self as *mut ::core::primitive::#repr)
+        #vis const fn checked_mul_div(
+            self,
+            mul: ::core::primitive::#repr,
+            div: ::core::primitive::#repr,
+        ) -> ::core::option::Option<Self> {
+            #body
         }
     });
 }
 
-fn generate_inherent_operators(item: &BoundedInteger, tokens: &mut TokenStream) {
-    let vis = &item.vis;
+fn generate_rounding_division(item: &BoundedInteger, tokens: &mut TokenStream) {
     let repr = &item.repr;
+    let vis = &item.vis;
 
-    if item.repr.signed {
-        tokens.extend(quote! {
-            /// Computes the absolute value of `self`, panicking if it is out of range.
-            #[must_use]
-            #[inline]
-            #vis fn abs(self) -> Self {
-                Self::new(self.get().abs()).expect("Absolute value out of range")
+    tokens.extend(quote! {
+        /// Checked division rounding towards negative infinity. Returns [`None`] if `rhs` is zero,
+        /// the division overflows or the quotient is out of range.
+        #[must_use]
+        #[inline]
+        #vis const fn checked_div_floor(
+            self,
+            rhs: ::core::primitive::#repr,
+        ) -> ::core::option::Option<Self> {
+            let n = self.get();
+            let (quotient, remainder) = match (n.checked_div(rhs), n.checked_rem(rhs)) {
+                (::core::option::Option::Some(q), ::core::option::Option::Some(r)) => (q, r),
+                _ => return ::core::option::Option::None,
+            };
+            // Truncation rounded up if the exact quotient is negative and not whole.
+            if remainder != 0 && (remainder > 0) != (rhs > 0) {
+                Self::new(quotient - 1)
+            } else {
+                Self::new(quotient)
             }
-        });
-    }
+        }
+
+        /// Checked division rounding towards positive infinity. Returns [`None`] if `rhs` is zero,
+        /// the division overflows or the quotient is out of range.
+        #[must_use]
+        #[inline]
+        #vis const fn checked_div_ceil(
+            self,
+            rhs: ::core::primitive::#repr,
+        ) -> ::core::option::Option<Self> {
+            let n = self.get();
+            let (quotient, remainder) = match (n.checked_div(rhs), n.checked_rem(rhs)) {
+                (::core::option::Option::Some(q), ::core::option::Option::Some(r)) => (q, r),
+                _ => return ::core::option::Option::None,
+            };
+            // Truncation rounded down if the exact quotient is positive and not whole.
+            if remainder != 0 && (remainder > 0) == (rhs > 0) {
+                Self::new(quotient + 1)
+            } else {
+                Self::new(quotient)
+            }
+        }
+    });
+}
+
+fn generate_min_max_with(item: &BoundedInteger, tokens: &mut TokenStream) {
+    let repr = &item.repr;
+    let vis = &item.vis;
 
     tokens.extend(quote! {
-        /// Raises `self` to the power of `exp`, using exponentiation by squaring. Panics if it
-        /// is out of range.
+        /// Returns the smaller of the value and `v`. As `v` may be below the range, the result
+        /// saturates at [`MIN`](Self::MIN).
         #[must_use]
         #[inline]
-        #vis fn pow(self, exp: ::core::primitive::u32) -> Self {
-            Self::new(self.get().pow(exp)).expect("Value raised to power out of range")
+        #vis const fn min_with(self, v: ::core::primitive::#repr) -> Self {
+            if self.get() <= v {
+                self
+            } else {
+                Self::new_saturating(v)
+            }
         }
-        /// Calculates the quotient of Euclidean division of `self` by `rhs`. Panics if `rhs`
-        /// is 0 or the result is out of range.
+
+        /// Returns the larger of the value and `v`. As `v` may be above the range, the result
+        /// saturates at [`MAX`](Self::MAX).
         #[must_use]
         #[inline]
-        #vis fn div_euclid(self, rhs: ::core::primitive::#repr) -> Self {
-            Self::new(self.get().div_euclid(rhs)).expect("Attempted to divide out of range")
+        #vis const fn max_with(self, v: ::core::primitive::#repr) -> Self {
+            if self.get() >= v {
+                self
+            } else {
+                Self::new_saturating(v)
+            }
         }
-        /// Calculates the least nonnegative remainder of `self (mod rhs)`. Panics if `rhs` is 0
-        /// or the result is out of range.
+
+        /// Returns the midpoint of `self` and `other`, rounded towards negative infinity. This
+        /// never overflows, and as it lies between two in-range values it is always in range.
         #[must_use]
         #[inline]
-        #vis fn rem_euclid(self, rhs: ::core::primitive::#repr) -> Self {
-            Self::new(self.get().rem_euclid(rhs))
-                .expect("Attempted to divide with remainder out of range")
+        #vis const fn midpoint_with(self, other: Self) -> Self {
+            let (a, b) = (self.get(), other.get());
+            match Self::new((a & b) + ((a ^ b) >> 1)) {
+                ::core::option::Option::Some(mid) => mid,
+                ::core::option::Option::None => ::core::unreachable!(),
+            }
         }
     });
 }
 
-fn generate_checked_operators(item: &BoundedInteger, tokens: &mut TokenStream) {
+fn generate_try_sum(item: &BoundedInteger, tokens: &mut TokenStream) {
     let vis = &item.vis;
 
-    for op in CHECKED_OPERATORS {
-        if !item.repr.signed && op.variants == OpVariants::Signed {
-            continue;
+    tokens.extend(quote! {
+        /// Sums the bounded integers, returning [`None`] if any of the partial sums is out of
+        /// range. The sum of no values is zero, so it is [`None`] if zero is out of range.
+        #[must_use]
+        #vis fn try_sum(
+            iter: impl ::core::iter::IntoIterator<Item = Self>,
+        ) -> ::core::option::Option<Self> {
+            let mut iter = ::core::iter::IntoIterator::into_iter(iter);
+            let first = match ::core::iter::Iterator::next(&mut iter) {
+                ::core::option::Option::Some(first) => first,
+                ::core::option::Option::None => return Self::new(0),
+            };
+            ::core::iter::Iterator::try_fold(&mut iter, first, |sum, n| sum.checked_add(n.get()))
         }
 
-        let rhs = match op.rhs {
-            Some("Self") => Some({
-                let repr = &item.repr;
-                quote!(::core::primitive::#repr)
-            }),
-            Some(name) => Some({
-                let ident = Ident::new(name, Span::call_site());
-                quote!(::core::primitive::#ident)
-            }),
-            None => None,
-        };
-        let rhs_type = rhs.as_ref().map(|ty| quote!(rhs: #ty,));
-        let rhs_value = rhs.map(|_| quote!(rhs,));
-
-        let checked_name = Ident::new(&format!("checked_{}", op.name), Span::call_site());
-        let checked_comment = format!("Checked {}.", op.description.trim());
-
-        tokens.extend(quote! {
-            #[doc = #checked_comment]
-            #[must_use]
-            #[inline]
-            #vis const fn #checked_name(self, #rhs_type) -> ::core::option::Option<Self> {
-                match self.get().#checked_name(#rhs_value) {
-                    ::core::option::Option::Some(val) => Self::new(val),
-                    ::core::option::Option::None => ::core::option::Option::None,
+        /// Folds every value in the range, in ascending order, into an accumulator.
+        #vis fn fold_values<T>(
+            init: T,
+            mut f: impl ::core::ops::FnMut(T, Self) -> T,
+        ) -> T {
+            let mut acc = init;
+            let mut n = Self::MIN_VALUE;
+            loop {
+                // SAFETY: `n` stays between `MIN_VALUE` and `MAX_VALUE`.
+                acc = f(acc, unsafe { Self::new_unchecked(n) });
+                if n == Self::MAX_VALUE {
+                    return acc;
                 }
+                n += 1;
             }
-        });
-
-        if op.variants == OpVariants::NoSaturating
-            || !item.repr.signed && op.variants == OpVariants::SignedSaturating
-        {
-            continue;
         }
-
-        let saturating_name = Ident::new(&format!("saturating_{}", op.name), Span::call_site());
-        let saturating_comment = format!("Saturating {}.", op.description.trim());
-
-        tokens.extend(quote! {
-            #[doc = #saturating_comment]
-            #[must_use]
-            #[inline]
-            #vis const fn #saturating_name(self, #rhs_type) -> Self {
-                Self::new_saturating(self.get().#saturating_name(#rhs_value))
-            }
-        });
-    }
+    });
 }
 
 struct CheckedOperator {
@@ -584,6 +2028,18 @@ fn generate_ops_traits(item: &BoundedInteger, tokens: &mut TokenStream) {
             );
         }
     }
+
+    // Bitwise not can only be infallible when every value of the repr is in range.
+    if is_full_range(item) {
+        unop_trait_variations(
+            &quote!(Not),
+            &quote!(not),
+            &item.ident,
+            // SAFETY: The bounded integer covers the whole range of the repr.
+            &quote!(unsafe { Self::new_unchecked(!self.get()) }),
+            tokens,
+        );
+    }
 }
 
 fn binop_trait_variations<B: ToTokens>(
@@ -595,9 +2051,9 @@ fn binop_trait_variations<B: ToTokens>(
     tokens: &mut TokenStream,
 ) {
     let trait_name = Ident::new(trait_name_root, Span::call_site());
-    let trait_name_assign = Ident::new(&format!("{}Assign", trait_name_root), Span::call_site());
+    let trait_name_assign = Ident::new(&format!("{trait_name_root}Assign"), Span::call_site());
     let method = Ident::new(method_root, Span::call_site());
-    let method_assign = Ident::new(&format!("{}_assign", method_root), Span::call_site());
+    let method_assign = Ident::new(&format!("{method_root}_assign"), Span::call_site());
     let body = body(&trait_name, &method);
 
     tokens.extend(quote! {
@@ -612,34 +2068,34 @@ fn binop_trait_variations<B: ToTokens>(
             type Output = #lhs;
             #[inline]
             fn #method(self, rhs: #rhs) -> Self::Output {
-                <#lhs as ::core::ops::#trait_name<#rhs>>::#method(*self, rhs)
+                <#lhs as ::core::ops::#trait_name<#rhs>>::#method(<#lhs as ::core::clone::Clone>::clone(self), rhs)
             }
         }
         impl<'b> ::core::ops::#trait_name<&'b #rhs> for #lhs {
             type Output = #lhs;
             #[inline]
             fn #method(self, rhs: &'b #rhs) -> Self::Output {
-                <#lhs as ::core::ops::#trait_name<#rhs>>::#method(self, *rhs)
+                <#lhs as ::core::ops::#trait_name<#rhs>>::#method(self, <#rhs as ::core::clone::Clone>::clone(rhs))
             }
         }
         impl<'a> ::core::ops::#trait_name<&'a #rhs> for &#lhs {
             type Output = #lhs;
             #[inline]
             fn #method(self, rhs: &'a #rhs) -> Self::Output {
-                <#lhs as ::core::ops::#trait_name<#rhs>>::#method(*self, *rhs)
+                <#lhs as ::core::ops::#trait_name<#rhs>>::#method(<#lhs as ::core::clone::Clone>::clone(self), <#rhs as ::core::clone::Clone>::clone(rhs))
             }
         }
 
         impl ::core::ops::#trait_name_assign<#rhs> for #lhs {
             #[inline]
             fn #method_assign(&mut self, rhs: #rhs) {
-                *self = <Self as ::core::ops::#trait_name<#rhs>>::#method(*self, rhs);
+                *self = <Self as ::core::ops::#trait_name<#rhs>>::#method(<#lhs as ::core::clone::Clone>::clone(self), rhs);
             }
         }
         impl<'a> ::core::ops::#trait_name_assign<&'a #rhs> for #lhs {
             #[inline]
             fn #method_assign(&mut self, rhs: &'a #rhs) {
-                *self = <Self as ::core::ops::#trait_name<#rhs>>::#method(*self, *rhs);
+                *self = <Self as ::core::ops::#trait_name<#rhs>>::#method(<#lhs as ::core::clone::Clone>::clone(self), <#rhs as ::core::clone::Clone>::clone(rhs));
             }
         }
     });
@@ -664,7 +2120,7 @@ fn unop_trait_variations(
             type Output = #lhs;
             #[inline]
             fn #method(self) -> Self::Output {
-                <#lhs as ::core::ops::#trait_name>::#method(*self)
+                <#lhs as ::core::ops::#trait_name>::#method(<#lhs as ::core::clone::Clone>::clone(self))
             }
         }
     });
@@ -692,6 +2148,10 @@ fn generate_cmp_traits(item: &BoundedInteger, tokens: &mut TokenStream) {
     let ident = &item.ident;
     let repr = &item.repr;
 
+    if item.comparisons < Comparisons::Eq {
+        return;
+    }
+
     // These are only impls that can't be derived
     tokens.extend(quote! {
         impl ::core::cmp::PartialEq<::core::primitive::#repr> for #ident {
@@ -706,6 +2166,48 @@ fn generate_cmp_traits(item: &BoundedInteger, tokens: &mut TokenStream) {
                 *self == other.get()
             }
         }
+    });
+
+    // The comparisons with itself are derived, except for a niche struct, whose field is a pattern
+    // type that doesn't implement them.
+    let niche = item.niche.is_some();
+    if niche {
+        tokens.extend(quote! {
+            impl ::core::cmp::PartialEq for #ident {
+                #[inline]
+                fn eq(&self, other: &Self) -> bool {
+                    self.get() == other.get()
+                }
+            }
+            impl ::core::cmp::Eq for #ident {}
+        });
+    }
+
+    if item.comparisons < Comparisons::Ord {
+        return;
+    }
+
+    if niche {
+        tokens.extend(quote! {
+            impl ::core::cmp::PartialOrd for #ident {
+                #[inline]
+                fn partial_cmp(
+                    &self,
+                    other: &Self,
+                ) -> ::core::option::Option<::core::cmp::Ordering> {
+                    ::core::option::Option::Some(::core::cmp::Ord::cmp(self, other))
+                }
+            }
+            impl ::core::cmp::Ord for #ident {
+                #[inline]
+                fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                    ::core::cmp::Ord::cmp(&self.get(), &other.get())
+                }
+            }
+        });
+    }
+
+    tokens.extend(quote! {
         impl ::core::cmp::PartialOrd<::core::primitive::#repr> for #ident {
             #[inline]
             fn partial_cmp(
@@ -717,31 +2219,175 @@ fn generate_cmp_traits(item: &BoundedInteger, tokens: &mut TokenStream) {
         }
         impl ::core::cmp::PartialOrd<#ident> for ::core::primitive::#repr {
             #[inline]
-            fn partial_cmp(
-                &self,
-                other: &#ident
-            ) -> ::core::option::Option<::core::cmp::Ordering> {
-                ::core::cmp::PartialOrd::partial_cmp(self, &other.get())
+            fn partial_cmp(
+                &self,
+                other: &#ident
+            ) -> ::core::option::Option<::core::cmp::Ordering> {
+                ::core::cmp::PartialOrd::partial_cmp(self, &other.get())
+            }
+        }
+    });
+}
+
+fn generate_types_eq(item: &BoundedInteger, tokens: &mut TokenStream) {
+    let ident = &item.ident;
+    let crate_path = &item.crate_path;
+
+    if item.comparisons < Comparisons::Eq {
+        return;
+    }
+
+    let sign = if item.repr.signed { "I" } else { "U" };
+    let name = Ident::new(
+        &format!("Bounded{sign}{}", item.repr.size),
+        Span::call_site(),
+    );
+    let (min, max) = if item.const_range.is_some() {
+        (quote!(#ident::MIN_VALUE), quote!(#ident::MAX_VALUE))
+    } else {
+        (
+            item.repr
+                .number_literal(item.range.start())
+                .into_token_stream(),
+            item.repr
+                .number_literal(item.range.end())
+                .into_token_stream(),
+        )
+    };
+    let types_equivalent = quote!(#crate_path::#name<{ #min }, { #max }>);
+
+    tokens.extend(quote! {
+        #crate_path::__private::cfg_types! {
+            impl ::core::cmp::PartialEq<#types_equivalent> for #ident {
+                #[inline]
+                fn eq(&self, other: &#types_equivalent) -> bool {
+                    self.get() == other.get()
+                }
+            }
+            impl ::core::cmp::PartialEq<#ident> for #types_equivalent {
+                #[inline]
+                fn eq(&self, other: &#ident) -> bool {
+                    self.get() == other.get()
+                }
+            }
+        }
+    });
+}
+
+fn generate_hash(item: &BoundedInteger, tokens: &mut TokenStream) {
+    let ident = &item.ident;
+    let repr = &item.repr;
+
+    // This must hash identically to the repr for the `Borrow` implementation to be correct.
+    tokens.extend(quote! {
+        impl ::core::hash::Hash for #ident {
+            #[inline]
+            fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                <::core::primitive::#repr as ::core::hash::Hash>::hash(&self.get(), state);
+            }
+        }
+    });
+}
+
+fn generate_as_ref_borrow(item: &BoundedInteger, tokens: &mut TokenStream) {
+    let ident = &item.ident;
+    let repr = &item.repr;
+
+    if item.offset_storage.is_some() {
+        return;
+    }
+
+    tokens.extend(quote! {
+        impl ::core::convert::AsRef<::core::primitive::#repr> for #ident {
+            #[inline]
+            fn as_ref(&self) -> &::core::primitive::#repr {
+                self.get_ref()
+            }
+        }
+        impl ::core::borrow::Borrow<::core::primitive::#repr> for #ident {
+            #[inline]
+            fn borrow(&self) -> &::core::primitive::#repr {
+                self.get_ref()
+            }
+        }
+    });
+}
+
+fn generate_char_conversions(item: &BoundedInteger, tokens: &mut TokenStream) {
+    let ident = &item.ident;
+    let repr = &item.repr;
+    let crate_path = &item.crate_path;
+
+    // Only ranges of Unicode scalar values can be converted to `char`.
+    let (start, end) = (item.range.start(), item.range.end());
+    let surrogates = BigInt::from(0xD800)..=BigInt::from(0xDFFF);
+    if item.const_range.is_some()
+        || *start < BigInt::from(0)
+        || *end > BigInt::from(u32::from(char::MAX))
+        || (*start <= *surrogates.end() && *end >= *surrogates.start())
+    {
+        return;
+    }
+
+    let min = Literal::u32_suffixed(u32::try_from(start).unwrap());
+    let max = Literal::u32_suffixed(u32::try_from(end).unwrap());
+
+    tokens.extend(quote! {
+        impl ::core::convert::TryFrom<::core::primitive::char> for #ident {
+            type Error = #crate_path::TryFromError;
+
+            #[inline]
+            fn try_from(c: ::core::primitive::char) -> ::core::result::Result<Self, Self::Error> {
+                let n = c as ::core::primitive::u32;
+                if !(#min..=#max).contains(&n) {
+                    return ::core::result::Result::Err(#crate_path::__private::try_from_error());
+                }
+                // SAFETY: The code point was checked to be within the range.
+                ::core::result::Result::Ok(unsafe { Self::new_unchecked(n as ::core::primitive::#repr) })
+            }
+        }
+        impl ::core::convert::From<#ident> for ::core::primitive::char {
+            #[inline]
+            fn from(value: #ident) -> Self {
+                // SAFETY: The range only contains Unicode scalar values.
+                unsafe { ::core::primitive::char::from_u32_unchecked(value.get() as ::core::primitive::u32) }
             }
         }
     });
 }
 
-fn generate_as_ref_borrow(item: &BoundedInteger, tokens: &mut TokenStream) {
+fn generate_nonzero_conversions(item: &BoundedInteger, tokens: &mut TokenStream) {
+    // Only a range without zero can always be converted to a non-zero integer.
+    if item.range.contains(&BigInt::from(0)) {
+        return;
+    }
+
     let ident = &item.ident;
-    let repr = &item.repr;
+    let crate_path = &item.crate_path;
+    let repr_name = item.repr.name.to_string();
+    let nonzero = Ident::new(
+        &format!(
+            "NonZero{}{}",
+            repr_name[..1].to_uppercase(),
+            &repr_name[1..]
+        ),
+        Span::call_site(),
+    );
 
     tokens.extend(quote! {
-        impl ::core::convert::AsRef<::core::primitive::#repr> for #ident {
+        impl ::core::convert::From<#ident> for ::core::num::#nonzero {
             #[inline]
-            fn as_ref(&self) -> &::core::primitive::#repr {
-                self.get_ref()
+            fn from(value: #ident) -> Self {
+                // SAFETY: Zero is not in the range.
+                unsafe { Self::new_unchecked(value.get()) }
             }
         }
-        impl ::core::borrow::Borrow<::core::primitive::#repr> for #ident {
+        impl ::core::convert::TryFrom<::core::num::#nonzero> for #ident {
+            type Error = #crate_path::TryFromError;
+
             #[inline]
-            fn borrow(&self) -> &::core::primitive::#repr {
-                self.get_ref()
+            fn try_from(n: ::core::num::#nonzero) -> ::core::result::Result<Self, Self::Error> {
+                Self::new(n.get()).ok_or_else(#crate_path::__private::try_from_error)
             }
         }
     });
@@ -750,7 +2396,7 @@ fn generate_as_ref_borrow(item: &BoundedInteger, tokens: &mut TokenStream) {
 fn generate_default(item: &BoundedInteger, tokens: &mut TokenStream) {
     let ident = &item.ident;
 
-    if item.range.contains(&BigInt::from(0)) {
+    if contains(item, 0) {
         tokens.extend(quote! {
             impl ::core::default::Default for #ident {
                 fn default() -> Self {
@@ -764,8 +2410,13 @@ fn generate_default(item: &BoundedInteger, tokens: &mut TokenStream) {
 fn generate_iter_traits(item: &BoundedInteger, tokens: &mut TokenStream, features: Features) {
     let ident = &item.ident;
     let repr = &item.repr;
+    let (owned, get) = if item.no_copy {
+        (quote!(cloned), quote!(|value| #ident::get(&value)))
+    } else {
+        (quote!(copied), quote!(#ident::get))
+    };
 
-    if item.range.contains(&BigInt::from(0)) {
+    if contains(item, 0) {
         tokens.extend(quote! {
             impl ::core::iter::Sum for #ident {
                 fn sum<I: ::core::iter::Iterator<Item = Self>>(iter: I) -> Self {
@@ -778,7 +2429,7 @@ fn generate_iter_traits(item: &BoundedInteger, tokens: &mut TokenStream, feature
             }
             impl<'a> ::core::iter::Sum<&'a Self> for #ident {
                 fn sum<I: ::core::iter::Iterator<Item = &'a Self>>(iter: I) -> Self {
-                    ::core::iter::Iterator::sum(::core::iter::Iterator::copied(iter))
+                    ::core::iter::Iterator::sum(::core::iter::Iterator::#owned(iter))
                 }
             }
         });
@@ -787,17 +2438,17 @@ fn generate_iter_traits(item: &BoundedInteger, tokens: &mut TokenStream, feature
     tokens.extend(quote! {
         impl ::core::iter::Sum<#ident> for ::core::primitive::#repr {
             fn sum<I: ::core::iter::Iterator<Item = #ident>>(iter: I) -> Self {
-                ::core::iter::Iterator::sum(::core::iter::Iterator::map(iter, #ident::get))
+                ::core::iter::Iterator::sum(::core::iter::Iterator::map(iter, #get))
             }
         }
         impl<'a> ::core::iter::Sum<&'a #ident> for ::core::primitive::#repr {
             fn sum<I: ::core::iter::Iterator<Item = &'a #ident>>(iter: I) -> Self {
-                ::core::iter::Iterator::sum(::core::iter::Iterator::copied(iter))
+                ::core::iter::Iterator::sum(::core::iter::Iterator::#owned(iter))
             }
         }
     });
 
-    if item.range.contains(&BigInt::from(1)) {
+    if contains(item, 1) {
         tokens.extend(quote! {
             impl ::core::iter::Product for #ident {
                 fn product<I: ::core::iter::Iterator<Item = Self>>(iter: I) -> Self {
@@ -810,7 +2461,7 @@ fn generate_iter_traits(item: &BoundedInteger, tokens: &mut TokenStream, feature
             }
             impl<'a> ::core::iter::Product<&'a Self> for #ident {
                 fn product<I: ::core::iter::Iterator<Item = &'a Self>>(iter: I) -> Self {
-                    ::core::iter::Iterator::product(::core::iter::Iterator::copied(iter))
+                    ::core::iter::Iterator::product(::core::iter::Iterator::#owned(iter))
                 }
             }
         });
@@ -819,17 +2470,18 @@ fn generate_iter_traits(item: &BoundedInteger, tokens: &mut TokenStream, feature
     tokens.extend(quote! {
         impl ::core::iter::Product<#ident> for ::core::primitive::#repr {
             fn product<I: ::core::iter::Iterator<Item = #ident>>(iter: I) -> Self {
-                ::core::iter::Iterator::product(::core::iter::Iterator::map(iter, #ident::get))
+                ::core::iter::Iterator::product(::core::iter::Iterator::map(iter, #get))
             }
         }
         impl<'a> ::core::iter::Product<&'a #ident> for ::core::primitive::#repr {
             fn product<I: ::core::iter::Iterator<Item = &'a #ident>>(iter: I) -> Self {
-                ::core::iter::Iterator::product(::core::iter::Iterator::copied(iter))
+                ::core::iter::Iterator::product(::core::iter::Iterator::#owned(iter))
             }
         }
     });
 
-    if features.step_trait {
+    // `Step` requires `PartialOrd`.
+    if features.step_trait && item.comparisons >= Comparisons::Ord {
         tokens.extend(quote! {
             impl ::core::iter::Step for #ident {
                 #[inline]
@@ -853,11 +2505,42 @@ fn generate_fmt_traits(item: &BoundedInteger, tokens: &mut TokenStream) {
     let ident = &item.ident;
     let repr = &item.repr;
 
+    if item.enum_facade {
+        // Show the variant, like the derived `Debug` of an enum.
+        tokens.extend(quote! {
+            impl ::core::fmt::Debug for #ident {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                    f.write_str(self.variant_name())
+                }
+            }
+        });
+    } else if has_manual_debug(item) {
+        let name = ident.to_string();
+        tokens.extend(quote! {
+            impl ::core::fmt::Debug for #ident {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                    f.debug_tuple(#name).field(&self.get()).finish()
+                }
+            }
+        });
+    }
+
     for &fmt_trait in &[
         "Binary", "Display", "LowerExp", "LowerHex", "Octal", "UpperExp", "UpperHex",
     ] {
         let fmt_trait = Ident::new(fmt_trait, Span::call_site());
 
+        if let (Some(format), "Display") = (&item.display, &*fmt_trait.to_string()) {
+            tokens.extend(quote! {
+                impl ::core::fmt::Display for #ident {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                        ::core::write!(f, #format, self.get())
+                    }
+                }
+            });
+            continue;
+        }
+
         tokens.extend(quote! {
             impl ::core::fmt::#fmt_trait for #ident {
                 fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
@@ -868,6 +2551,22 @@ fn generate_fmt_traits(item: &BoundedInteger, tokens: &mut TokenStream) {
     }
 }
 
+fn generate_from_str(item: &BoundedInteger, tokens: &mut TokenStream) {
+    let ident = &item.ident;
+    let crate_path = &item.crate_path;
+
+    tokens.extend(quote! {
+        impl ::core::str::FromStr for #ident {
+            type Err = #crate_path::ParseError;
+            /// Parses the bounded integer like the primitive does, accepting a leading `+` or `-`,
+            /// and then checks that it is in range.
+            fn from_str(s: &::core::primitive::str) -> ::core::result::Result<Self, Self::Err> {
+                Self::from_ascii(s.as_bytes())
+            }
+        }
+    });
+}
+
 fn generate_to_primitive_traits(item: &BoundedInteger, tokens: &mut TokenStream) {
     let ident = &item.ident;
 
@@ -883,51 +2582,239 @@ fn generate_to_primitive_traits(item: &BoundedInteger, tokens: &mut TokenStream)
     }
 }
 
-fn generate_serde(item: &BoundedInteger, tokens: &mut TokenStream) {
+fn generate_to_string(item: &BoundedInteger, tokens: &mut TokenStream) {
+    // A custom format is only applied through `Display`, which `ToString` uses.
+    if item.display.is_some() {
+        return;
+    }
+
+    let ident = &item.ident;
+    let vis = &item.vis;
+    let crate_path = &item.crate_path;
+    let private = quote!(#crate_path::__private);
+
+    let (negative, magnitude) = if item.repr.signed {
+        (quote!(self.get() < 0), quote!(self.get().unsigned_abs()))
+    } else {
+        (quote!(false), quote!(self.get()))
+    };
+
+    tokens.extend(quote! {
+        #private::cfg_alloc! {
+            impl #ident {
+                /// Converts the bounded integer to a string in decimal, without going through the
+                /// [`Display`](::core::fmt::Display) machinery.
+                #[must_use]
+                #vis fn to_string(&self) -> #private::String {
+                    #private::to_string(#negative, #magnitude as ::core::primitive::u128)
+                }
+            }
+        }
+    });
+}
+
+fn generate_from_bytes(item: &BoundedInteger, tokens: &mut TokenStream) {
+    // Enums derive `TryFromBytes` instead, and the other structs don't implement the zerocopy
+    // traits at all.
+    if !matches!(item.kind, Kind::Struct(_)) || !has_repr_layout(item) || item.niche.is_some() {
+        return;
+    }
+
     let ident = &item.ident;
     let repr = &item.repr;
+    let vis = &item.vis;
     let crate_path = &item.crate_path;
-    let serde = quote!(#crate_path::__private::serde);
+    let private = quote!(#crate_path::__private);
+    let from_bytes = quote!(<::core::primitive::#repr as #private::zerocopy::FromBytes>);
 
     tokens.extend(quote! {
-        impl #serde::Serialize for #ident {
-            fn serialize<S>(&self, serializer: S) -> ::core::result::Result<
-                <S as #serde::Serializer>::Ok,
-                <S as #serde::Serializer>::Error,
-            >
-            where
-                S: #serde::Serializer,
-            {
-                <::core::primitive::#repr as #serde::Serialize>::serialize(&self.get(), serializer)
+        #private::cfg_zerocopy! {
+            impl #ident {
+                /// Reads a bounded integer from bytes, like zerocopy's
+                /// `TryFromBytes::try_read_from_bytes`. Returns `None` if `bytes` has the wrong
+                /// length or holds a value outside the range
+                /// [[`MIN`](Self::MIN), [`MAX`](Self::MAX)].
+                #[must_use]
+                #[inline]
+                #vis fn try_read_from_bytes(
+                    bytes: &[::core::primitive::u8],
+                ) -> ::core::option::Option<Self> {
+                    Self::new(#from_bytes::read_from_bytes(bytes).ok()?)
+                }
+
+                /// Interprets bytes as a reference to a bounded integer, like zerocopy's
+                /// `TryFromBytes::try_ref_from_bytes`. Returns `None` if `bytes` has the wrong
+                /// length or alignment or holds a value outside the range
+                /// [[`MIN`](Self::MIN), [`MAX`](Self::MAX)].
+                #[must_use]
+                #[inline]
+                #vis fn try_ref_from_bytes(
+                    bytes: &[::core::primitive::u8],
+                ) -> ::core::option::Option<&Self> {
+                    Self::new_ref(#from_bytes::ref_from_bytes(bytes).ok()?)
+                }
+
+                /// Interprets bytes as a mutable reference to a bounded integer, like zerocopy's
+                /// `TryFromBytes::try_mut_from_bytes`. Returns `None` if `bytes` has the wrong
+                /// length or alignment or holds a value outside the range
+                /// [[`MIN`](Self::MIN), [`MAX`](Self::MAX)].
+                #[must_use]
+                #[inline]
+                #vis fn try_mut_from_bytes(
+                    bytes: &mut [::core::primitive::u8],
+                ) -> ::core::option::Option<&mut Self> {
+                    Self::new_mut(#from_bytes::mut_from_bytes(bytes).ok()?)
+                }
             }
         }
     });
+}
+
+fn generate_bounded_integer_trait(item: &BoundedInteger, tokens: &mut TokenStream) {
+    let ident = &item.ident;
+    let repr = &item.repr;
+    let crate_path = &item.crate_path;
+
+    // The trait requires `Copy` and `Ord`.
+    if item.no_copy || item.comparisons < Comparisons::Ord {
+        return;
+    }
 
     tokens.extend(quote! {
-        impl<'de> #serde::Deserialize<'de> for #ident {
-            fn deserialize<D>(deserializer: D) -> ::core::result::Result<
-                Self,
-                <D as #serde::Deserializer<'de>>::Error,
-            >
-            where
-                D: #serde::Deserializer<'de>,
-            {
-                let value = <::core::primitive::#repr as #serde::Deserialize<'de>>::deserialize(deserializer)?;
-                Self::new(value)
-                    .ok_or_else(|| {
-                        <<D as #serde::Deserializer<'de>>::Error as #serde::de::Error>::custom(
-                            ::core::format_args!(
-                                "integer out of range, expected it to be between {} and {}",
-                                Self::MIN_VALUE,
-                                Self::MAX_VALUE,
-                            )
-                        )
-                    })
+        impl #crate_path::BoundedInteger for #ident {
+            type Repr = ::core::primitive::#repr;
+
+            const MIN_VALUE: ::core::primitive::#repr = Self::MIN_VALUE;
+            const MAX_VALUE: ::core::primitive::#repr = Self::MAX_VALUE;
+
+            #[inline]
+            fn new(n: ::core::primitive::#repr) -> ::core::option::Option<Self> {
+                Self::new(n)
+            }
+            #[inline]
+            fn new_saturating(n: ::core::primitive::#repr) -> Self {
+                Self::new_saturating(n)
+            }
+            #[inline]
+            fn get(self) -> ::core::primitive::#repr {
+                Self::get(self)
             }
         }
     });
 }
 
+fn generate_serde(item: &BoundedInteger, tokens: &mut TokenStream) {
+    let ident = &item.ident;
+    let repr = &item.repr;
+    let crate_path = &item.crate_path;
+    let serde = quote!(#crate_path::__private::serde);
+
+    if item.serde.offset {
+        generate_serde_offset(item, tokens);
+        return;
+    }
+
+    if !item.serde.deserialize_only {
+        tokens.extend(quote! {
+            impl #serde::Serialize for #ident {
+                fn serialize<S>(&self, serializer: S) -> ::core::result::Result<
+                    <S as #serde::Serializer>::Ok,
+                    <S as #serde::Serializer>::Error,
+                >
+                where
+                    S: #serde::Serializer,
+                {
+                    <::core::primitive::#repr as #serde::Serialize>::serialize(&self.get(), serializer)
+                }
+            }
+        });
+    }
+
+    if !item.serde.serialize_only {
+        tokens.extend(quote! {
+            impl<'de> #serde::Deserialize<'de> for #ident {
+                fn deserialize<D>(deserializer: D) -> ::core::result::Result<
+                    Self,
+                    <D as #serde::Deserializer<'de>>::Error,
+                >
+                where
+                    D: #serde::Deserializer<'de>,
+                {
+                    let value = <::core::primitive::#repr as #serde::Deserialize<'de>>::deserialize(deserializer)?;
+                    Self::new(value)
+                        .ok_or_else(|| {
+                            <<D as #serde::Deserializer<'de>>::Error as #serde::de::Error>::custom(
+                                ::core::format_args!(
+                                    "integer out of range, expected it to be between {} and {}",
+                                    Self::MIN_VALUE,
+                                    Self::MAX_VALUE,
+                                )
+                            )
+                        })
+                }
+            }
+        });
+    }
+}
+
+/// Generate serde implementations that represent the value as its offset from the minimum, which
+/// is smaller in variable-length encodings when the minimum is large.
+fn generate_serde_offset(item: &BoundedInteger, tokens: &mut TokenStream) {
+    let ident = &item.ident;
+    let repr = &item.repr;
+    let unsigned = Repr::new(false, item.repr.size);
+    let crate_path = &item.crate_path;
+    let serde = quote!(#crate_path::__private::serde);
+
+    if !item.serde.deserialize_only {
+        tokens.extend(quote! {
+            impl #serde::Serialize for #ident {
+                fn serialize<S>(&self, serializer: S) -> ::core::result::Result<
+                    <S as #serde::Serializer>::Ok,
+                    <S as #serde::Serializer>::Error,
+                >
+                where
+                    S: #serde::Serializer,
+                {
+                    let offset = self.get().wrapping_sub(Self::MIN_VALUE) as ::core::primitive::#unsigned;
+                    <::core::primitive::#unsigned as #serde::Serialize>::serialize(&offset, serializer)
+                }
+            }
+        });
+    }
+
+    if !item.serde.serialize_only {
+        tokens.extend(quote! {
+            impl<'de> #serde::Deserialize<'de> for #ident {
+                fn deserialize<D>(deserializer: D) -> ::core::result::Result<
+                    Self,
+                    <D as #serde::Deserializer<'de>>::Error,
+                >
+                where
+                    D: #serde::Deserializer<'de>,
+                {
+                    let offset = <::core::primitive::#unsigned as #serde::Deserialize<'de>>::deserialize(deserializer)?;
+                    let max_offset = Self::MAX_VALUE.wrapping_sub(Self::MIN_VALUE) as ::core::primitive::#unsigned;
+                    if offset > max_offset {
+                        return ::core::result::Result::Err(
+                            <<D as #serde::Deserializer<'de>>::Error as #serde::de::Error>::custom(
+                                ::core::format_args!(
+                                    "offset out of range, expected it to be at most {}",
+                                    max_offset,
+                                )
+                            )
+                        );
+                    }
+                    // SAFETY: Every offset up to the maximum offset is in range.
+                    ::core::result::Result::Ok(unsafe {
+                        Self::new_unchecked(Self::MIN_VALUE.wrapping_add(offset as ::core::primitive::#repr))
+                    })
+                }
+            }
+        });
+    }
+}
+
 fn generate_tests(item: &BoundedInteger, tokens: &mut TokenStream) {
     let mut tests = TokenStream::new();
 
@@ -952,8 +2839,13 @@ fn generate_test_range(item: &BoundedInteger, tokens: &mut TokenStream) {
     let min = item.repr.number_literal(item.range.start());
     let max = item.repr.number_literal(item.range.end());
 
-    let above_min = item.repr.number_literal(item.range.start() + 1);
-    let below_max = item.repr.number_literal(item.range.end() - 1);
+    // These are clamped to the range for single-value ranges.
+    let above_min = item
+        .repr
+        .number_literal(cmp::min(item.range.start() + 1, item.range.end().clone()));
+    let below_max = item
+        .repr
+        .number_literal(cmp::max(item.range.end() - 1, item.range.start().clone()));
 
     let opt_literal = |num| {
         if let Ok(lit) = item.repr.try_number_literal(num) {
@@ -991,20 +2883,20 @@ fn generate_test_range(item: &BoundedInteger, tokens: &mut TokenStream) {
 
         #[test]
         fn saturating() {
-            assert_eq!(#ident::new_saturating(#repr::MIN), #ident::MIN_VALUE);
+            assert_eq!(#ident::new_saturating(#repr::MIN).get(), #ident::MIN_VALUE);
             if let Some(below_range) = #below_range {
-                assert_eq!(#ident::new_saturating(below_range), #ident::MIN_VALUE);
+                assert_eq!(#ident::new_saturating(below_range).get(), #ident::MIN_VALUE);
             }
-            assert_eq!(#ident::new_saturating(#min), #ident::MIN_VALUE);
+            assert_eq!(#ident::new_saturating(#min).get(), #ident::MIN_VALUE);
 
             assert_eq!(#ident::new_saturating(#above_min).get(), #above_min);
             assert_eq!(#ident::new_saturating(#below_max).get(), #below_max);
 
-            assert_eq!(#ident::new_saturating(#max), #ident::MAX_VALUE);
+            assert_eq!(#ident::new_saturating(#max).get(), #ident::MAX_VALUE);
             if let Some(above_range) = #above_range {
-                assert_eq!(#ident::new_saturating(above_range), #ident::MAX_VALUE);
+                assert_eq!(#ident::new_saturating(above_range).get(), #ident::MAX_VALUE);
             }
-            assert_eq!(#ident::new_saturating(#repr::MAX), #ident::MAX_VALUE);
+            assert_eq!(#ident::new_saturating(#repr::MAX).get(), #ident::MAX_VALUE);
         }
     });
 }
@@ -1051,6 +2943,9 @@ fn generate_test_arithmetic(item: &BoundedInteger, tokens: &mut TokenStream) {
     }
     body.extend(quote! {
         let _: Option<#ident> = #ident::MIN.checked_neg();
+        let _: Option<#ident> = #ident::MIN.checked_not();
+        let _: Option<#ident> = #ident::MIN.checked_increment();
+        let _: Option<#ident> = #ident::MIN.checked_decrement();
     });
 
     let infallibles = [
@@ -1079,7 +2974,7 @@ fn generate_test_arithmetic(item: &BoundedInteger, tokens: &mut TokenStream) {
         });
     }
     for method in &fallibles {
-        let method = Ident::new(&format!("checked_{}", method), Span::call_site());
+        let method = Ident::new(&format!("checked_{method}"), Span::call_site());
         body.extend(quote! {
             let _: Option<#ident> = #ident::MIN.#method(0);
         });
@@ -1095,12 +2990,102 @@ fn generate_test_arithmetic(item: &BoundedInteger, tokens: &mut TokenStream) {
     });
 }
 
-fn enum_variant(i: &BigInt) -> Ident {
+/// Whether the type has the same layout as its repr, which is not the case if it has a custom
+/// alignment or is zero-sized.
+fn has_repr_layout(item: &BoundedInteger) -> bool {
+    item.align.is_none() && !item.zst && item.offset_storage.is_none()
+}
+
+fn is_zst_struct(item: &BoundedInteger) -> bool {
+    item.zst && matches!(item.kind, Kind::Struct(_))
+}
+
+/// Whether the derived `Debug` would not show the value, as the struct stores nothing or only an
+/// offset, could not be derived, as the field is a pattern type, or would not show the variant of
+/// a large enum.
+fn has_manual_debug(item: &BoundedInteger) -> bool {
+    is_zst_struct(item) || item.offset_storage.is_some() || item.niche.is_some() || item.enum_facade
+}
+
+/// The type of a non-zero-sized struct's field. With `#[niche]` this is a pattern type that tells
+/// the compiler which values are stored, so it can use the others as a niche.
+fn struct_field_type(item: &BoundedInteger) -> TokenStream {
+    let storage = item.offset_storage.as_ref().unwrap_or(&item.repr);
+    let Some(niche) = &item.niche else {
+        return quote!(::core::primitive::#storage);
+    };
+    let (low, high) = (
+        storage.number_literal(niche.start()),
+        storage.number_literal(niche.end()),
+    );
+    quote!(::core::pattern_type!(::core::primitive::#storage is #low..=#high))
+}
+
+/// Reads the primitive stored in a non-zero-sized struct's field.
+fn struct_stored_value(item: &BoundedInteger) -> TokenStream {
+    if item.niche.is_some() {
+        let storage = item.offset_storage.as_ref().unwrap_or(&item.repr);
+        let field_type = struct_field_type(item);
+        // SAFETY: A pattern type has the same layout as its primitive.
+        quote!(unsafe {
+            ::core::mem::transmute::<#field_type, ::core::primitive::#storage>(self.0)
+        })
+    } else {
+        quote!(self.0)
+    }
+}
+
+/// Constructs a non-zero-sized struct from an expression giving a value in its range.
+fn struct_from_value(item: &BoundedInteger, value: &TokenStream) -> TokenStream {
+    let field = if let Some(storage) = &item.offset_storage {
+        quote!(#value.wrapping_sub(Self::MIN_VALUE) as ::core::primitive::#storage)
+    } else {
+        value.clone()
+    };
+    struct_from_field(item, &field)
+}
+
+/// Constructs a non-zero-sized struct from a value in its range.
+fn struct_from_literal(item: &BoundedInteger, value: &BigInt) -> TokenStream {
+    let literal = match &item.offset_storage {
+        Some(storage) => storage.number_literal(value - item.range.start()),
+        None => item.repr.number_literal(value),
+    };
+    struct_from_field(item, &literal.into_token_stream())
+}
+
+/// Constructs a non-zero-sized struct from the primitive stored in its field, which must be in
+/// range.
+fn struct_from_field(item: &BoundedInteger, field: &TokenStream) -> TokenStream {
+    if item.niche.is_some() {
+        let storage = item.offset_storage.as_ref().unwrap_or(&item.repr);
+        let field_type = struct_field_type(item);
+        // SAFETY: The value is in range, so it is valid for the pattern type.
+        quote!(Self(unsafe {
+            ::core::mem::transmute::<::core::primitive::#storage, #field_type>(#field)
+        }))
+    } else {
+        quote!(Self(#field))
+    }
+}
+
+/// Whether the value is known to be in range, which it never is for a range given as a constant.
+fn contains(item: &BoundedInteger, value: i32) -> bool {
+    item.const_range.is_none() && item.range.contains(&BigInt::from(value))
+}
+
+fn is_full_range(item: &BoundedInteger) -> bool {
+    item.const_range.is_none()
+        && item.repr.minimum().as_ref() == Some(item.range.start())
+        && item.repr.maximum().as_ref() == Some(item.range.end())
+}
+
+pub(crate) fn enum_variant(i: &BigInt) -> Ident {
     Ident::new(
-        &*match i.sign() {
+        &match i.sign() {
             num_bigint::Sign::Minus => format!("N{}", i.magnitude()),
             num_bigint::Sign::NoSign => "Z".to_owned(),
-            num_bigint::Sign::Plus => format!("P{}", i),
+            num_bigint::Sign::Plus => format!("P{i}"),
         },
         Span::call_site(),
     )
@@ -1118,7 +3103,7 @@ mod tests {
     ) {
         let item = match parse2::<BoundedInteger>(quote!([::path] #input)) {
             Ok(item) => item,
-            Err(e) => panic!("Failed to parse '{}': {}", input.to_string(), e),
+            Err(e) => panic!("Failed to parse '{}': {}", input, e),
         };
         let mut result = TokenStream::new();
         f(&item, &mut result);
@@ -1127,19 +3112,57 @@ mod tests {
     }
 
     #[test]
-    fn test_tokens() {
-        let derives = quote! {
+    fn full_range_constructors() {
+        for input in [
+            quote!(struct S { 0..=255 }),
+            quote!(#[repr(i16)] struct S { -32768..32768 }),
+        ] {
+            let item = parse2::<BoundedInteger>(quote!([::path] #input)).unwrap();
+            let mut result = TokenStream::new();
+            generate_checked_constructors(&item, &mut result);
+            let result = result.to_string();
+
+            // `new` and `new_saturating` come before `new_ref`.
+            let constructors = &result[..result.find("fn new_ref").unwrap()];
+            assert!(!constructors.contains("in_range"), "{}", constructors);
+            assert!(!constructors.contains("MIN_VALUE"), "{}", constructors);
+            assert!(!constructors.contains("MAX_VALUE"), "{}", constructors);
+        }
+    }
+
+    #[test]
+    fn large_enum_is_struct() {
+        for (input, is_enum) in [
+            (quote!(enum S { 0..128 }), true),
+            (quote!(enum S { 0..=128 }), false),
+            (quote!(enum S { 0..=255 }), false),
+        ] {
+            let item = parse2::<BoundedInteger>(quote!([::path] #input)).unwrap();
+            let mut result = TokenStream::new();
+            generate_item(&item, &mut result);
+            let result = result.to_string();
+            assert_eq!(result.contains("enum S"), is_enum, "{result}");
+            assert_eq!(result.contains("struct S"), !is_enum, "{result}");
+        }
+    }
+
+    fn derives() -> TokenStream {
+        quote! {
             #[derive(
                 ::core::fmt::Debug,
-                ::core::hash::Hash,
                 ::core::clone::Clone,
                 ::core::marker::Copy,
                 ::core::cmp::PartialEq,
                 ::core::cmp::Eq,
                 ::core::cmp::PartialOrd,
-                ::core::cmp::Ord
+                ::core::cmp::Ord,
             )]
-        };
+        }
+    }
+
+    #[test]
+    fn test_tokens() {
+        let derives = derives();
 
         assert_result(
             generate_item,
@@ -1171,6 +3194,20 @@ mod tests {
             },
         );
 
+        assert_result(
+            generate_item,
+            quote! {
+                enum Nibble { start: 3, len: 5 }
+            },
+            quote! {
+                #derives
+                #[repr(u8)]
+                enum Nibble {
+                    P3 = 3u8, P4, P5, P6, P7
+                }
+            },
+        );
+
         assert_result(
             generate_item,
             quote! {
@@ -1184,4 +3221,93 @@ mod tests {
             },
         );
     }
+
+    #[test]
+    fn test_tokens_layout() {
+        let derives = derives();
+
+        assert_result(
+            generate_item,
+            quote! {
+                #[repr(u8, align(4))]
+                enum Nibble { 3..=7 }
+            },
+            quote! {
+                #derives
+                #[repr(u8, align(4))]
+                enum Nibble {
+                    P3 = 3u8, P4, P5, P6, P7
+                }
+            },
+        );
+
+        assert_result(
+            generate_item,
+            quote! {
+                #[repr(C, u8)]
+                enum Nibble { 3..=7 }
+            },
+            quote! {
+                #derives
+                #[repr(u8)]
+                enum Nibble {
+                    P3 = 3u8, P4, P5, P6, P7
+                }
+            },
+        );
+
+        assert_result(
+            generate_item,
+            quote! {
+                #[repr(C)]
+                enum Nibble { -1..=1 }
+            },
+            quote! {
+                #derives
+                #[repr(C)]
+                enum Nibble {
+                    N1 = -1isize, Z, P1
+                }
+            },
+        );
+
+        assert_result(
+            generate_item,
+            quote! {
+                #[repr(C)]
+                struct S { 3..=7 }
+            },
+            quote! {
+                #derives
+                #[repr(C)]
+                struct S(::core::primitive::u8);
+            },
+        );
+
+        assert_result(
+            generate_item,
+            quote! {
+                #[repr(align(8))]
+                struct S { 3..=7 }
+            },
+            quote! {
+                #derives
+                #[repr(C, align(8))]
+                struct S(::core::primitive::u8);
+            },
+        );
+
+        assert_result(
+            generate_item,
+            quote! {
+                #[niche]
+                struct S { -3..2 }
+            },
+            quote! {
+                #[derive(::core::clone::Clone, ::core::marker::Copy,)]
+                #[repr(transparent)]
+                struct S(::core::pattern_type!(::core::primitive::i8 is -3i8..=1i8));
+            },
+        );
+    }
 }
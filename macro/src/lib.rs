@@ -12,11 +12,12 @@ use std::ops::RangeInclusive;
 use proc_macro2::{Group, Ident, Literal, Span, TokenStream};
 use quote::{quote, ToTokens, TokenStreamExt as _};
 use syn::parse::{self, Parse, ParseStream};
-use syn::{braced, parse_macro_input, token::Brace, Token};
-use syn::{Attribute, Error, Expr, PathArguments, PathSegment, Visibility};
+use syn::{braced, parenthesized, parse_macro_input, token::Brace, Token};
+use syn::{Attribute, Error, Expr, LitInt, PathArguments, PathSegment, Visibility};
 use syn::{BinOp, ExprBinary, ExprRange, ExprUnary, RangeLimits, UnOp};
-use syn::{ExprGroup, ExprParen};
-use syn::{ExprLit, Lit};
+use syn::{ExprCall, ExprGroup, ExprParen, ExprPath};
+use syn::{ExprLit, Lit, LitStr};
+use syn::{GenericArgument, Type, TypePath};
 
 use num_bigint::{BigInt, TryFromBigIntError};
 
@@ -75,20 +76,68 @@ fn bounded_integer(input: proc_macro::TokenStream, features: Features) -> proc_m
     let ident = &item.ident;
     let original_visibility = item.vis;
 
-    let import = quote!(#original_visibility use #module_name::#ident);
+    // The item is accompanied by a module and an import, which must be configured out with it.
+    let cfgs: TokenStream = item
+        .attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("cfg"))
+        .map(ToTokens::to_token_stream)
+        .collect();
 
-    item.vis = raise_one_level(original_visibility);
+    // The bounds of a range given as a constant are declared where the macro was invoked, so that
+    // the path to the constant resolves as it was written.
+    let (repr, module) = (&item.repr, &item.module);
+    let const_range_bounds = item.const_range.as_ref().map(|range| {
+        let ty = match module {
+            Some(module) => quote!(#module::#ident),
+            None => ident.to_token_stream(),
+        };
+        quote! {
+            #cfgs
+            impl #ty {
+                /// The smallest value that this bounded integer can contain; the start of the
+                /// range constant it was declared with.
+                #original_visibility const MIN_VALUE: ::core::primitive::#repr = *#range.start();
+                /// The largest value that this bounded integer can contain; the end of the range
+                /// constant it was declared with.
+                #original_visibility const MAX_VALUE: ::core::primitive::#repr = *#range.end();
+            }
+        }
+    });
+
+    // With `#[in_module]`, everything is generated one level deeper inside the given module.
+    let (outer_visibility, visibility) = match &item.module {
+        Some(_) => (
+            Some(original_visibility.to_token_stream()),
+            raise_one_level(original_visibility),
+        ),
+        None => (None, original_visibility),
+    };
+
+    let import = quote!(#cfgs #visibility use #module_name::#ident);
+
+    item.vis = raise_one_level(visibility);
     let mut result = TokenStream::new();
     generate::generate(&item, &mut result, features);
 
-    quote!(
+    let result = quote!(
+        #cfgs
         #[allow(non_snake_case)]
         mod #module_name {
             #result
         }
         #import;
-    )
-    .into()
+    );
+
+    let result = match &item.module {
+        Some(module) if item.reexport => quote! {
+            #cfgs #outer_visibility mod #module { #result }
+            #cfgs #outer_visibility use #module::#ident;
+        },
+        Some(module) => quote!(#cfgs #outer_visibility mod #module { #result }),
+        None => result,
+    };
+    quote!(#result #const_range_bounds).into()
 }
 
 macro_rules! signed {
@@ -100,10 +149,46 @@ macro_rules! signed {
     };
 }
 
+// The flags are independent options set by separate attributes.
+#[allow(clippy::struct_excessive_bools)]
 struct BoundedInteger {
     crate_path: TokenStream,
     attrs: Vec<Attribute>,
     repr: Repr,
+    /// Whether the type has the C representation, set by `#[repr(C)]`.
+    repr_c: bool,
+    /// Whether the type is an enum given `#[repr(C)]` without a primitive, so its discriminant is
+    /// a C `int`.
+    c_int: bool,
+    align: Option<LitInt>,
+    /// Whether the type is zero-sized, which happens for single-value ranges without a `repr`.
+    zst: bool,
+    /// The unsigned primitive that stores the value's offset from the minimum instead of the value
+    /// itself, set by `#[repr(offset)]`.
+    offset_storage: Option<Repr>,
+    /// The options given in a `#[serde(...)]` attribute.
+    serde: SerdeAttr,
+    /// The format string of the `Display` implementation, set by `#[display(format = "...")]`.
+    display: Option<LitStr>,
+    /// The module to place the type in, set by `#[in_module(...)]` or `#[namespace(...)]`.
+    module: Option<Ident>,
+    /// Whether to re-export the type from the module, set by using `#[namespace(...)]`.
+    reexport: bool,
+    /// The comparison traits to implement, reduced by `#[no_eq]` and `#[no_ord]`.
+    comparisons: Comparisons,
+    /// Whether to omit the `Copy` implementation, set by `#[no_copy]`.
+    no_copy: bool,
+    /// Whether to generate a constant for every value, set by `#[value_consts]`.
+    value_consts: bool,
+    /// The values that can be stored in the field, set by `#[niche]`. The field is then a pattern
+    /// type that leaves the other values for the compiler to use as a niche.
+    niche: Option<RangeInclusive<BigInt>>,
+    /// Whether the type was written as an enum, but has too many values for a variant each. It is
+    /// then generated as a struct with the associated items of an enum.
+    enum_facade: bool,
+    /// The path to the constant holding the range, if the range was given as one. `range` is then
+    /// the range of the repr, as the bounds are only known once the constant is evaluated.
+    const_range: Option<Expr>,
     vis: Visibility,
     kind: Kind,
     ident: Ident,
@@ -118,9 +203,34 @@ impl Parse for BoundedInteger {
         let mut attrs = input.call(Attribute::parse_outer)?;
 
         let repr_pos = attrs.iter().position(|attr| attr.path.is_ident("repr"));
-        let repr = repr_pos
-            .map(|pos| attrs.remove(pos).parse_args::<Repr>())
-            .transpose()?;
+        let ReprAttr {
+            c,
+            repr,
+            align,
+            offset,
+        } = repr_pos
+            .map(|pos| attrs.remove(pos).parse_args::<ReprAttr>())
+            .transpose()?
+            .unwrap_or_default();
+
+        let serde_pos = attrs.iter().position(|attr| attr.path.is_ident("serde"));
+        let serde = serde_pos
+            .map(|pos| attrs.remove(pos).parse_args::<SerdeAttr>())
+            .transpose()?
+            .unwrap_or_default();
+
+        let (module, reexport) = take_module_attr(&mut attrs)?;
+
+        let display_pos = attrs.iter().position(|attr| attr.path.is_ident("display"));
+        let display = display_pos
+            .map(|pos| attrs.remove(pos).parse_args::<DisplayAttr>())
+            .transpose()?
+            .map(|attr| attr.format);
+
+        let comparisons = take_comparisons_attrs(&mut attrs)?;
+        let no_copy = take_flag_attr(&mut attrs, "no_copy")?;
+        let value_consts = take_flag_attr(&mut attrs, "value_consts")?;
+        let niche = take_flag_attr(&mut attrs, "niche")?;
 
         let vis: Visibility = input.parse()?;
 
@@ -130,65 +240,70 @@ impl Parse for BoundedInteger {
 
         let range_tokens;
         let brace_token = braced!(range_tokens in input);
-        let range: ExprRange = range_tokens.parse()?;
+        let range: RangeInput = range_tokens.parse()?;
+        let unsupported = [(value_consts, "value_consts"), (niche, "niche")];
+        let (from, to) = range_bounds(&range, &kind, repr.as_ref(), offset.as_ref(), &unsupported)?;
+        let zst = from == to && !c && repr.is_none() && align.is_none();
 
-        let (from_expr, to_expr) = match range.from.as_deref().zip(range.to.as_deref()) {
-            Some(t) => t,
-            None => return Err(Error::new_spanned(range, "Range must be closed")),
-        };
-        let from = eval_expr(from_expr)?;
-        let to = eval_expr(to_expr)?;
-        let to = if let RangeLimits::HalfOpen(_) = range.limits {
-            to - 1
-        } else {
-            to
-        };
-        if from >= to {
+        if let (Some(offset), Kind::Enum(_)) = (&offset, &kind) {
+            return Err(Error::new(
+                offset.span(),
+                "`#[repr(offset)]` is only supported on structs",
+            ));
+        }
+
+        if value_consts && &to - &from >= BigInt::from(VALUE_CONSTS_LIMIT) {
             return Err(Error::new_spanned(
-                range,
-                "The start of the range must be before the end",
+                &range,
+                format_args!("`#[value_consts]` supports at most {VALUE_CONSTS_LIMIT} values"),
             ));
         }
 
+        let c_int = c && repr.is_none() && matches!(kind, Kind::Enum(_));
         let repr = match repr {
             Some(explicit_repr) => {
-                if !explicit_repr.signed && from.sign() == num_bigint::Sign::Minus {
-                    return Err(Error::new_spanned(
-                        from_expr,
-                        "An unsigned integer cannot hold a negative value",
-                    ));
-                }
-
-                if explicit_repr.minimum().map_or(false, |min| from < min) {
-                    return Err(Error::new_spanned(
-                        from_expr,
-                        format_args!(
-                            "Bound {} is below the minimum value for the underlying type",
-                            from
-                        ),
-                    ));
-                }
-                if explicit_repr.maximum().map_or(false, |max| to > max) {
-                    return Err(Error::new_spanned(
-                        to_expr,
-                        format_args!(
-                            "Bound {} is above the maximum value for the underlying type",
-                            to
-                        ),
-                    ));
-                }
-
+                check_explicit_repr(&explicit_repr, &kind, &range, &from, &to)?;
                 explicit_repr
             }
+            None if c_int => c_int_repr(&range, &from, &to)?,
             None => Repr::smallest_repr(&from, &to).ok_or_else(|| {
-                Error::new_spanned(range, "Range is too wide to fit in any integer primitive")
+                Error::new_spanned(&range, "Range is too wide to fit in any integer primitive")
             })?,
         };
 
+        // A zero-sized type stores nothing, so it has no offset to store either.
+        let offset_storage = offset
+            .filter(|_| !zst)
+            .map(|_| Repr::smallest_repr(&BigInt::from(0), &(&to - &from)).unwrap());
+
+        let niche = niche
+            .then(|| {
+                let offset_storage = offset_storage.as_ref();
+                niche_range(&kind, zst, &repr, offset_storage, &range, &from, &to)
+            })
+            .transpose()?;
+
+        let (kind, enum_facade) = enum_facade(kind, &from, &to);
+
         Ok(Self {
             crate_path,
             attrs,
             repr,
+            repr_c: c,
+            c_int,
+            align,
+            zst,
+            offset_storage,
+            serde,
+            display,
+            module,
+            reexport,
+            comparisons,
+            no_copy,
+            value_consts,
+            niche,
+            enum_facade,
+            const_range: matches!(range.limits, RangeInputLimits::Const).then(|| range.from),
             vis,
             kind,
             ident,
@@ -198,6 +313,349 @@ impl Parse for BoundedInteger {
     }
 }
 
+/// Removes the `#[in_module(...)]` or `#[namespace(...)]` attribute, returning the module it names
+/// and whether the type is re-exported from it.
+fn take_module_attr(attrs: &mut Vec<Attribute>) -> parse::Result<(Option<Ident>, bool)> {
+    let mut take = |name| {
+        let pos = attrs.iter().position(|attr| attr.path.is_ident(name));
+        pos.map(|pos| attrs.remove(pos).parse_args::<Ident>())
+            .transpose()
+    };
+    match (take("in_module")?, take("namespace")?) {
+        (Some(_), Some(namespace)) => Err(Error::new_spanned(
+            namespace,
+            "`#[namespace]` cannot be combined with `#[in_module]`",
+        )),
+        (Some(module), None) => Ok((Some(module), false)),
+        (None, namespace) => Ok((namespace.clone(), namespace.is_some())),
+    }
+}
+
+/// The most values that `#[value_consts]` generates constants for.
+const VALUE_CONSTS_LIMIT: u32 = 256;
+
+/// The most values that an enum generates a variant for. Larger enums take long to compile, so they
+/// are generated as a struct instead.
+const ENUM_VARIANT_LIMIT: u32 = 128;
+
+/// Turns an enum with more than [`ENUM_VARIANT_LIMIT`] values into a struct, returning whether it
+/// did.
+fn enum_facade(kind: Kind, from: &BigInt, to: &BigInt) -> (Kind, bool) {
+    match kind {
+        Kind::Enum(token) if to - from >= BigInt::from(ENUM_VARIANT_LIMIT) => {
+            (Kind::Struct(Token![struct](token.span)), true)
+        }
+        kind => (kind, false),
+    }
+}
+
+/// The comparison traits implemented by the type. Each level includes the ones before it.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Comparisons {
+    None,
+    /// `PartialEq` and `Eq`.
+    Eq,
+    /// `PartialEq`, `Eq`, `PartialOrd` and `Ord`.
+    Ord,
+}
+
+/// Gives the inclusive bounds of the range. A range given as the path to a constant can't be
+/// evaluated by the macro, so it gives the bounds of the repr instead. Only structs with an
+/// explicit repr are supported then, and not the features that need to know the bounds while
+/// generating the code.
+fn range_bounds(
+    range: &RangeInput,
+    kind: &Kind,
+    repr: Option<&Repr>,
+    offset: Option<&Ident>,
+    unsupported: &[(bool, &str)],
+) -> parse::Result<(BigInt, BigInt)> {
+    if !matches!(range.limits, RangeInputLimits::Const) {
+        return range.evaluate();
+    }
+    if let Kind::Enum(token) = kind {
+        return Err(Error::new(
+            token.span,
+            "An enum needs its range written out, as it has a variant for every value",
+        ));
+    }
+    if let Some(offset) = offset {
+        return Err(Error::new(
+            offset.span(),
+            "`#[repr(offset)]` needs the range written out",
+        ));
+    }
+    if let Some((_, name)) = unsupported.iter().find(|(used, _)| *used) {
+        return Err(Error::new_spanned(
+            range,
+            format_args!("`#[{name}]` needs the range written out"),
+        ));
+    }
+    let repr = repr.ok_or_else(|| {
+        Error::new_spanned(
+            range,
+            "A range given as a constant needs an explicit primitive, as in #[repr(i32)]",
+        )
+    })?;
+    // A pointer-sized primitive is at most 64 bits wide.
+    let repr = match repr.size {
+        ReprSize::Pointer => Repr::new(repr.signed, ReprSize::Fixed(ReprSizeFixed::Fixed64)),
+        size @ ReprSize::Fixed(_) => Repr::new(repr.signed, size),
+    };
+    Ok((repr.minimum().unwrap(), repr.maximum().unwrap()))
+}
+
+/// Gives the primitive of an enum with `#[repr(C)]` and no other primitive, which has the size of a
+/// C `int`, checking that the range fits in it.
+fn c_int_repr(range: &RangeInput, from: &BigInt, to: &BigInt) -> parse::Result<Repr> {
+    let repr = Repr::new(true, ReprSize::Fixed(ReprSizeFixed::Fixed32));
+    if *from < repr.minimum().unwrap() || *to > repr.maximum().unwrap() {
+        let suggestion = Repr::smallest_repr(from, to).map_or_else(String::new, |repr| {
+            format!("; give the primitive, as in #[repr(C, {})]", repr.name)
+        });
+        return Err(Error::new_spanned(
+            range,
+            format_args!("Range does not fit in a C `int`{suggestion}"),
+        ));
+    }
+    Ok(repr)
+}
+
+/// Gives the values that can be stored in a struct with `#[niche]`, checking that there are other
+/// values of the primitive left for the niche.
+fn niche_range(
+    kind: &Kind,
+    zst: bool,
+    repr: &Repr,
+    offset_storage: Option<&Repr>,
+    range: &RangeInput,
+    from: &BigInt,
+    to: &BigInt,
+) -> parse::Result<RangeInclusive<BigInt>> {
+    if let Kind::Enum(token) = kind {
+        return Err(Error::new(
+            token.span,
+            "`#[niche]` is only supported on structs; enums already have a niche",
+        ));
+    }
+    if zst {
+        return Err(Error::new_spanned(
+            range,
+            "`#[niche]` cannot be used on a zero-sized type",
+        ));
+    }
+    // An offset struct stores values from zero up to the width of the range.
+    let (storage, low, high) = match offset_storage {
+        Some(storage) => (storage, BigInt::from(0), to - from),
+        None => (repr, from.clone(), to.clone()),
+    };
+    if storage.minimum().as_ref() == Some(&low) && storage.maximum().as_ref() == Some(&high) {
+        return Err(Error::new_spanned(
+            range,
+            format_args!(
+                "`#[niche]` needs a range narrower than `{}`, as it leaves no values for the niche",
+                storage.name
+            ),
+        ));
+    }
+    Ok(low..=high)
+}
+
+/// Checks that every value of the range fits in an explicitly given repr.
+fn check_explicit_repr(
+    repr: &Repr,
+    kind: &Kind,
+    range: &RangeInput,
+    from: &BigInt,
+    to: &BigInt,
+) -> parse::Result<()> {
+    let suggestion = || {
+        Repr::smallest_repr(from, to).map_or_else(String::new, |repr| {
+            format!("; consider #[repr({})]", repr.name)
+        })
+    };
+
+    // Every value is a discriminant of an enum, so name the variant that doesn't fit.
+    let describe = |bound: &BigInt, limit: &str| match kind {
+        Kind::Struct(_) => format!("Bound {bound} is {limit} value for the underlying type"),
+        Kind::Enum(_) => format!(
+            "The discriminant {bound} of variant `{}` is {limit} value of `{}`",
+            generate::enum_variant(bound),
+            repr.name,
+        ),
+    };
+
+    if !repr.signed && from.sign() == num_bigint::Sign::Minus {
+        return Err(Error::new_spanned(
+            &range.from,
+            format_args!(
+                "An unsigned integer cannot hold a negative value{}",
+                suggestion(),
+            ),
+        ));
+    }
+
+    if repr.minimum().is_some_and(|min| *from < min) {
+        return Err(Error::new_spanned(
+            &range.from,
+            format_args!("{}{}", describe(from, "below the minimum"), suggestion()),
+        ));
+    }
+    if repr.maximum().is_some_and(|max| *to > max) {
+        return Err(Error::new_spanned(
+            &range.to,
+            format_args!("{}{}", describe(to, "above the maximum"), suggestion()),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Removes an attribute that takes no arguments, like `#[no_eq]`, returning whether it was present.
+fn take_flag_attr(attrs: &mut Vec<Attribute>, name: &str) -> parse::Result<bool> {
+    let Some(pos) = attrs.iter().position(|attr| attr.path.is_ident(name)) else {
+        return Ok(false);
+    };
+    let attr = attrs.remove(pos);
+    if !attr.tokens.is_empty() {
+        return Err(Error::new_spanned(
+            attr.tokens,
+            format_args!("`#[{name}]` does not take arguments"),
+        ));
+    }
+    Ok(true)
+}
+
+/// Removes the `#[no_eq]` and `#[no_ord]` attributes, returning the comparisons they leave.
+fn take_comparisons_attrs(attrs: &mut Vec<Attribute>) -> parse::Result<Comparisons> {
+    let no_eq = take_flag_attr(attrs, "no_eq")?;
+    let no_ord = take_flag_attr(attrs, "no_ord")?;
+    Ok(if no_eq {
+        Comparisons::None
+    } else if no_ord {
+        Comparisons::Eq
+    } else {
+        Comparisons::Ord
+    })
+}
+
+mod kw {
+    syn::custom_keyword!(start);
+    syn::custom_keyword!(len);
+}
+
+/// The range of the bounded integer as written in the input, either as `x..y`, `x..=y`,
+/// `start: x, len: y` or the path to a constant.
+struct RangeInput {
+    from: Expr,
+    to: Expr,
+    limits: RangeInputLimits,
+}
+
+enum RangeInputLimits {
+    /// `x..=y`
+    Closed,
+    /// `x..y`
+    HalfOpen,
+    /// `start: x, len: y`
+    Length,
+    /// `RANGE`, a path to a `RangeInclusive` constant. Both `from` and `to` hold the path.
+    Const,
+}
+
+impl Parse for RangeInput {
+    fn parse(input: ParseStream<'_>) -> parse::Result<Self> {
+        if input.peek(kw::start) {
+            input.parse::<kw::start>()?;
+            input.parse::<Token![:]>()?;
+            let from = input.parse()?;
+            input.parse::<Token![,]>()?;
+            input.parse::<kw::len>()?;
+            input.parse::<Token![:]>()?;
+            let to = input.parse()?;
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+            return Ok(Self {
+                from,
+                to,
+                limits: RangeInputLimits::Length,
+            });
+        }
+
+        let fork = input.fork();
+        if fork.parse::<ExprPath>().is_ok() && fork.is_empty() {
+            let to = Expr::Path(input.fork().parse()?);
+            return Ok(Self {
+                from: Expr::Path(input.parse()?),
+                to,
+                limits: RangeInputLimits::Const,
+            });
+        }
+
+        let range: ExprRange = input.parse()?;
+        if range.from.is_none() || range.to.is_none() {
+            return Err(Error::new_spanned(range, "Range must be closed"));
+        }
+        let limits = match range.limits {
+            RangeLimits::Closed(_) => RangeInputLimits::Closed,
+            RangeLimits::HalfOpen(_) => RangeInputLimits::HalfOpen,
+        };
+        Ok(Self {
+            from: *range.from.unwrap(),
+            to: *range.to.unwrap(),
+            limits,
+        })
+    }
+}
+
+impl RangeInput {
+    /// Evaluate the inclusive bounds of the range.
+    fn evaluate(&self) -> syn::Result<(BigInt, BigInt)> {
+        let from = eval_expr(&self.from)?;
+        let to = eval_expr(&self.to)?;
+        let to = match self.limits {
+            RangeInputLimits::Closed => to,
+            RangeInputLimits::HalfOpen => {
+                if to <= from {
+                    return Err(Error::new_spanned(
+                        self,
+                        format_args!("The exclusive range `{from}..{to}` is empty"),
+                    ));
+                }
+                to - 1
+            }
+            RangeInputLimits::Length => {
+                if to.sign() != num_bigint::Sign::Plus {
+                    return Err(Error::new_spanned(
+                        &self.to,
+                        "The length of the range must be positive",
+                    ));
+                }
+                &from + to - 1
+            }
+            // The macro can't evaluate a constant; `range_bounds` gives its bounds instead.
+            RangeInputLimits::Const => unreachable!(),
+        };
+        if from > to {
+            return Err(Error::new_spanned(
+                self,
+                "The start of the range must not be after the end",
+            ));
+        }
+        Ok((from, to))
+    }
+}
+
+impl ToTokens for RangeInput {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.from.to_tokens(tokens);
+        if !matches!(self.limits, RangeInputLimits::Const) {
+            self.to.to_tokens(tokens);
+        }
+    }
+}
+
 enum Kind {
     Struct(Token![struct]),
     Enum(Token![enum]),
@@ -213,6 +671,171 @@ impl Parse for Kind {
     }
 }
 
+/// The contents of a `#[repr(...)]` attribute on the item.
+#[derive(Default)]
+struct ReprAttr {
+    c: bool,
+    repr: Option<Repr>,
+    align: Option<LitInt>,
+    /// The `offset` keyword, if given.
+    offset: Option<Ident>,
+}
+
+impl Parse for ReprAttr {
+    fn parse(input: ParseStream<'_>) -> parse::Result<Self> {
+        let mut this = Self::default();
+
+        while !input.is_empty() {
+            let ident = input.fork().parse::<Ident>()?;
+
+            if ident == "C" {
+                input.parse::<Ident>()?;
+                if this.c {
+                    return Err(Error::new(ident.span(), "Duplicate C representation"));
+                }
+                this.c = true;
+            } else if ident == "offset" {
+                input.parse::<Ident>()?;
+                if this.offset.replace(ident.clone()).is_some() {
+                    return Err(Error::new(ident.span(), "Duplicate offset representation"));
+                }
+            } else if ident == "align" {
+                input.parse::<Ident>()?;
+                let content;
+                parenthesized!(content in input);
+                let align = content.parse::<LitInt>()?;
+                if !align.base10_parse::<u32>()?.is_power_of_two() {
+                    return Err(Error::new_spanned(
+                        align,
+                        "Alignment must be a power of two",
+                    ));
+                }
+                if this.align.replace(align).is_some() {
+                    return Err(Error::new(ident.span(), "Duplicate alignment"));
+                }
+            } else if this.repr.replace(input.parse()?).is_some() {
+                return Err(Error::new(ident.span(), "Duplicate repr type"));
+            }
+
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+
+        Ok(this)
+    }
+}
+
+/// The contents of a `#[serde(...)]` attribute on the item.
+#[derive(Default)]
+struct SerdeAttr {
+    /// Whether to serialize the value as its offset from the minimum.
+    offset: bool,
+    /// Whether to only implement `Serialize`.
+    serialize_only: bool,
+    /// Whether to only implement `Deserialize`.
+    deserialize_only: bool,
+}
+
+impl Parse for SerdeAttr {
+    fn parse(input: ParseStream<'_>) -> parse::Result<Self> {
+        let mut this = Self::default();
+
+        while !input.is_empty() {
+            let ident = input.parse::<Ident>()?;
+
+            let option = if ident == "offset" {
+                &mut this.offset
+            } else if ident == "serialize_only" {
+                &mut this.serialize_only
+            } else if ident == "deserialize_only" {
+                &mut this.deserialize_only
+            } else {
+                return Err(Error::new(ident.span(), "Unknown serde option"));
+            };
+            if *option {
+                return Err(Error::new(
+                    ident.span(),
+                    format_args!("Duplicate serde option `{ident}`"),
+                ));
+            }
+            *option = true;
+
+            if this.serialize_only && this.deserialize_only {
+                return Err(Error::new(
+                    ident.span(),
+                    "`serialize_only` and `deserialize_only` cannot be used together",
+                ));
+            }
+
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+
+        Ok(this)
+    }
+}
+
+/// The options given in a `#[display(...)]` attribute.
+struct DisplayAttr {
+    format: LitStr,
+}
+
+impl Parse for DisplayAttr {
+    fn parse(input: ParseStream<'_>) -> parse::Result<Self> {
+        let ident = input.parse::<Ident>()?;
+        if ident != "format" {
+            return Err(Error::new(ident.span(), "Unknown display option"));
+        }
+        input.parse::<Token![=]>()?;
+        let format = input.parse::<LitStr>()?;
+        if !input.is_empty() {
+            input.parse::<Token![,]>()?;
+        }
+        if !input.is_empty() {
+            return Err(input.error("Expected only a `format` option"));
+        }
+
+        if count_placeholders(&format.value()) != Some(1) {
+            return Err(Error::new(
+                format.span(),
+                "The format string must contain exactly one `{}` placeholder",
+            ));
+        }
+
+        Ok(Self { format })
+    }
+}
+
+/// Counts the placeholders in a format string, which may have a format spec like `{:>5}` but not
+/// an argument name or position. Returns `None` if the string is malformed.
+fn count_placeholders(format: &str) -> Option<usize> {
+    let mut count = 0;
+    let mut chars = format.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' => {
+                let rest = chars.as_str();
+                if rest.starts_with('{') {
+                    chars.next();
+                    continue;
+                }
+                let end = rest.find('}')?;
+                let placeholder = &rest[..end];
+                if !(placeholder.is_empty() || placeholder.starts_with(':')) {
+                    return None;
+                }
+                count += 1;
+                chars = rest[end + 1..].chars();
+            }
+            '}' if chars.next() != Some('}') => return None,
+            _ => {}
+        }
+    }
+    Some(count)
+}
+
 struct Repr {
     signed: bool,
     size: ReprSize,
@@ -358,8 +981,7 @@ impl Parse for Repr {
                 return Err(Error::new(
                     span,
                     format_args!(
-                        "Unknown integer size {}, must be one of 8, 16, 32, 64, 128 or size",
-                        unknown
+                        "Unknown integer size {unknown}, must be one of 8, 16, 32, 64, 128 or size"
                     ),
                 ));
             }
@@ -455,7 +1077,16 @@ impl Display for ReprSizeFixed {
 fn eval_expr(expr: &Expr) -> syn::Result<BigInt> {
     Ok(match expr {
         Expr::Lit(ExprLit { lit, .. }) => match lit {
-            Lit::Int(int) => int.base10_parse()?,
+            Lit::Int(int) => {
+                let suffix = int.suffix();
+                if !suffix.is_empty() && syn::parse_str::<Repr>(suffix).is_err() {
+                    return Err(Error::new_spanned(
+                        int,
+                        format_args!("invalid suffix `{suffix}` for integer literal"),
+                    ));
+                }
+                int.base10_parse()?
+            }
             _ => {
                 return Err(Error::new_spanned(lit, "literal must be integer"));
             }
@@ -497,10 +1128,81 @@ fn eval_expr(expr: &Expr) -> syn::Result<BigInt> {
         Expr::Group(ExprGroup { expr, .. }) | Expr::Paren(ExprParen { expr, .. }) => {
             eval_expr(expr)?
         }
+        Expr::Call(call) => eval_layout_call(call)?,
         _ => return Err(Error::new_spanned(expr, "expected simple expression")),
     })
 }
 
+/// Evaluates a call to `size_of` or `align_of` on a primitive type, since a proc macro cannot
+/// evaluate const fns itself. Only layouts that are the same on every target are accepted.
+fn eval_layout_call(call: &ExprCall) -> syn::Result<BigInt> {
+    let unsupported = || Error::new_spanned(call, "only `size_of` and `align_of` can be called");
+
+    let Expr::Path(ExprPath {
+        qself: None, path, ..
+    }) = &*call.func
+    else {
+        return Err(unsupported());
+    };
+    let last = path.segments.last().ok_or_else(unsupported)?;
+    let prefix: Vec<String> = path
+        .segments
+        .iter()
+        .take(path.segments.len() - 1)
+        .map(|segment| segment.ident.to_string())
+        .collect();
+    let prefix: Vec<&str> = prefix.iter().map(String::as_str).collect();
+    let is_size = last.ident == "size_of";
+    if !is_size && last.ident != "align_of"
+        || !matches!(&*prefix, [] | ["mem"] | ["core" | "std", "mem"])
+    {
+        return Err(unsupported());
+    }
+    if !call.args.is_empty() {
+        return Err(Error::new_spanned(&call.args, "expected no arguments"));
+    }
+
+    let ty = match &last.arguments {
+        PathArguments::AngleBracketed(args) if args.args.len() == 1 => match &args.args[0] {
+            GenericArgument::Type(Type::Path(TypePath { qself: None, path })) => path.get_ident(),
+            _ => None,
+        },
+        _ => None,
+    };
+    let ty = ty.ok_or_else(|| {
+        Error::new_spanned(
+            &last.arguments,
+            "expected a primitive type, as in `::<u32>`",
+        )
+    })?;
+
+    let (size, align) = match &*ty.to_string() {
+        "u8" | "i8" | "bool" => (1, Some(1)),
+        "u16" | "i16" => (2, None),
+        "u32" | "i32" | "f32" | "char" => (4, None),
+        "u64" | "i64" | "f64" => (8, None),
+        "u128" | "i128" => (16, None),
+        "usize" | "isize" => {
+            return Err(Error::new_spanned(
+                ty,
+                format_args!("the layout of `{ty}` depends on the target"),
+            ));
+        }
+        _ => return Err(Error::new_spanned(ty, "expected a primitive type")),
+    };
+
+    if is_size {
+        Ok(BigInt::from(size))
+    } else {
+        align.map(BigInt::from).ok_or_else(|| {
+            Error::new_spanned(
+                ty,
+                format_args!("the alignment of `{ty}` depends on the target"),
+            )
+        })
+    }
+}
+
 /// Raise a visibility one level.
 ///
 /// ```text
@@ -571,6 +1273,240 @@ fn test_raise_one_level() {
     );
 }
 
+#[cfg(test)]
+fn assert_error(input: &TokenStream, message: &str) {
+    let Err(error) = syn::parse2::<BoundedInteger>(quote!([::path] #input)) else {
+        panic!("Parsing '{}' succeeded", input);
+    };
+    assert_eq!(error.to_string(), message);
+}
+
+#[test]
+fn test_repr_suggestion() {
+    assert_error(
+        &quote!(#[repr(u8)] struct S { 0..=1000 }),
+        "Bound 1000 is above the maximum value for the underlying type; consider #[repr(u16)]",
+    );
+    assert_error(
+        &quote!(#[repr(i8)] struct S { -200..0 }),
+        "Bound -200 is below the minimum value for the underlying type; consider #[repr(i16)]",
+    );
+    assert_error(
+        &quote!(#[repr(u32)] enum S { -1..10 }),
+        "An unsigned integer cannot hold a negative value; consider #[repr(i8)]",
+    );
+}
+
+#[test]
+fn test_enum_repr_too_narrow() {
+    assert_error(
+        &quote!(#[repr(u8)] enum S { 250..=256 }),
+        "The discriminant 256 of variant `P256` is above the maximum value of `u8`; \
+         consider #[repr(u16)]",
+    );
+    assert_error(
+        &quote!(#[repr(i8)] enum S { -129..0 }),
+        "The discriminant -129 of variant `N129` is below the minimum value of `i8`; \
+         consider #[repr(i16)]",
+    );
+}
+
+#[test]
+fn test_empty_range() {
+    assert_error(
+        &quote!(struct S { 0..0 }),
+        "The exclusive range `0..0` is empty",
+    );
+    assert_error(
+        &quote!(struct S { 5..2 + 1 }),
+        "The exclusive range `5..3` is empty",
+    );
+    assert_error(
+        &quote!(struct S { 5..=4 }),
+        "The start of the range must not be after the end",
+    );
+}
+
+#[test]
+fn test_const_range() {
+    assert_error(
+        &quote!(struct S { RANGE }),
+        "A range given as a constant needs an explicit primitive, as in #[repr(i32)]",
+    );
+    assert_error(
+        &quote!(#[repr(i32)] enum S { config::RANGE }),
+        "An enum needs its range written out, as it has a variant for every value",
+    );
+    assert_error(
+        &quote!(#[repr(i32)] #[value_consts] struct S { RANGE }),
+        "`#[value_consts]` needs the range written out",
+    );
+    assert_error(
+        &quote!(#[repr(i32, offset)] struct S { RANGE }),
+        "`#[repr(offset)]` needs the range written out",
+    );
+}
+
+#[test]
+fn test_namespace_with_in_module() {
+    assert_error(
+        &quote!(#[in_module(a)] #[namespace(b)] enum S { 0..5 }),
+        "`#[namespace]` cannot be combined with `#[in_module]`",
+    );
+}
+
+#[test]
+fn test_repr_c() {
+    assert_error(
+        &quote!(#[repr(C)] enum S { 0..=3_000_000_000 }),
+        "Range does not fit in a C `int`; give the primitive, as in #[repr(C, u32)]",
+    );
+    assert_error(
+        &quote!(#[repr(C, u8, C)] enum S { 0..10 }),
+        "Duplicate C representation",
+    );
+    assert_error(
+        &quote!(#[repr(C, u8)] enum S { 0..300 }),
+        "The discriminant 299 of variant `P299` is above the maximum value of `u8`; \
+            consider #[repr(u16)]",
+    );
+}
+
+#[test]
+fn test_niche() {
+    assert_error(
+        &quote!(#[niche] enum S { 0..10 }),
+        "`#[niche]` is only supported on structs; enums already have a niche",
+    );
+    assert_error(
+        &quote!(#[niche] struct S { 5..=5 }),
+        "`#[niche]` cannot be used on a zero-sized type",
+    );
+    assert_error(
+        &quote!(#[niche] struct S { 0..=255 }),
+        "`#[niche]` needs a range narrower than `u8`, as it leaves no values for the niche",
+    );
+    assert_error(
+        &quote!(#[niche] #[repr(offset)] struct S { 1000..=1255 }),
+        "`#[niche]` needs a range narrower than `u8`, as it leaves no values for the niche",
+    );
+}
+
+#[test]
+fn test_repr_offset() {
+    assert_error(
+        &quote!(#[repr(offset)] enum S { -5..5 }),
+        "`#[repr(offset)]` is only supported on structs",
+    );
+    assert_error(
+        &quote!(#[repr(offset, offset)] struct S { -5..5 }),
+        "Duplicate offset representation",
+    );
+}
+
+#[test]
+fn test_number_literal_128() {
+    let unsigned = Repr::new(false, ReprSize::Fixed(ReprSizeFixed::Fixed128));
+    let max = unsigned.number_literal(BigInt::from(u128::MAX));
+    assert_eq!(max.to_string(), format!("{}u128", u128::MAX));
+    assert!(unsigned
+        .try_number_literal(BigInt::from(u128::MAX) + 1)
+        .is_err());
+
+    let signed = Repr::new(true, ReprSize::Fixed(ReprSizeFixed::Fixed128));
+    let min = signed.number_literal(BigInt::from(i128::MIN));
+    assert_eq!(min.to_string(), format!("{}i128", i128::MIN));
+    let max = signed.number_literal(BigInt::from(i128::MAX));
+    assert_eq!(max.to_string(), format!("{}i128", i128::MAX));
+    assert!(signed
+        .try_number_literal(BigInt::from(i128::MIN) - 1)
+        .is_err());
+}
+
+#[test]
+fn test_display_format() {
+    let message = "The format string must contain exactly one `{}` placeholder";
+    assert_error(
+        &quote!(#[display(format = "no value")] struct S { 0..1 }),
+        message,
+    );
+    assert_error(
+        &quote!(#[display(format = "{} and {}")] struct S { 0..1 }),
+        message,
+    );
+    assert_error(
+        &quote!(#[display(format = "{0}")] struct S { 0..1 }),
+        message,
+    );
+    assert_error(&quote!(#[display(format = "{")] struct S { 0..1 }), message);
+    assert_error(
+        &quote!(#[display(format = "}{}")] struct S { 0..1 }),
+        message,
+    );
+    assert_error(
+        &quote!(#[display(fmt = "{}")] struct S { 0..1 }),
+        "Unknown display option",
+    );
+
+    assert_eq!(count_placeholders("{}°C"), Some(1));
+    assert_eq!(count_placeholders("{{{:>3}}}"), Some(1));
+    assert_eq!(count_placeholders("{{}}"), Some(0));
+}
+
+#[test]
+fn test_value_consts_limit() {
+    assert_error(
+        &quote!(#[value_consts] struct S { 0..=256 }),
+        "`#[value_consts]` supports at most 256 values",
+    );
+    assert!(
+        syn::parse2::<BoundedInteger>(quote!([::path] #[value_consts] struct S { 0..256 })).is_ok()
+    );
+}
+
+#[test]
+fn test_layout_calls() {
+    let eval = |expr: Expr| eval_expr(&expr).unwrap();
+    assert_eq!(eval(syn::parse_quote!(size_of::<u32>())), BigInt::from(4));
+    assert_eq!(
+        eval(syn::parse_quote!(mem::size_of::<char>())),
+        BigInt::from(4)
+    );
+    assert_eq!(
+        eval(syn::parse_quote!(::core::mem::size_of::<i128>() * 8 - 1)),
+        BigInt::from(127)
+    );
+    assert_eq!(
+        eval(syn::parse_quote!(std::mem::align_of::<u8>())),
+        BigInt::from(1)
+    );
+
+    assert_error(
+        &quote!(struct S { 0..size_of::<usize>() }),
+        "the layout of `usize` depends on the target",
+    );
+    assert_error(
+        &quote!(struct S { 0..align_of::<u64>() }),
+        "the alignment of `u64` depends on the target",
+    );
+    assert_error(
+        &quote!(struct S { 0..size_of::<String>() }),
+        "expected a primitive type",
+    );
+    assert_error(
+        &quote!(struct S { 0..size_of() }),
+        "expected a primitive type, as in `::<u32>`",
+    );
+    assert_error(
+        &quote!(struct S { 0..other::size_of::<u8>() }),
+        "only `size_of` and `align_of` can be called",
+    );
+    assert_error(
+        &quote!(struct S { 0..max(1, 2) }),
+        "only `size_of` and `align_of` can be called",
+    );
+}
+
 enum Either<A, B> {
     A(A),
     B(B),
@@ -0,0 +1,291 @@
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::fmt::{self, Display, Formatter};
+
+use crate::traits::sealed::Bounds;
+use crate::{BoundedInteger, Primitive};
+
+/// A primitive integer `T` constrained to be in the range `MIN..=MAX`.
+///
+/// This is a single type generic over its primitive, as an alternative to [`BoundedU8`],
+/// [`BoundedI16`] and the other types with one primitive each. The type of a const generic
+/// parameter cannot depend on `T`, so the bounds are always given as `i128`; a `u128` bound above
+/// [`i128::MAX`] needs [`BoundedU128`] instead. Bounds that don't fit in `T`, or a `MIN` greater
+/// than `MAX`, fail to compile once the type is used.
+///
+/// As its methods are generic over the primitive, it has fewer of them than the types with one
+/// primitive each, and they aren't `const`. It implements [`BoundedInteger`], so the generic
+/// operations of that trait and [`Checked`](crate::Checked) work with it.
+///
+/// # Examples
+///
+/// ```
+#[cfg_attr(feature = "step_trait", doc = "# #![feature(step_trait)]")]
+/// use bounded_integer::{Bounded, BoundedInteger, BoundedU8};
+///
+/// let n = Bounded::<u8, 0, 7>::new(5).unwrap();
+/// assert_eq!(n.get(), 5);
+/// assert_eq!(Bounded::<u8, 0, 7>::new(8), None);
+/// assert_eq!(n.saturating_cast_to::<BoundedU8<0, 3>>(), 3);
+/// ```
+///
+/// [`BoundedU8`]: crate::BoundedU8
+/// [`BoundedI16`]: crate::BoundedI16
+/// [`BoundedU128`]: crate::BoundedU128
+#[cfg_attr(doc_cfg, doc(cfg(feature = "types")))]
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Bounded<T, const MIN: i128, const MAX: i128>(T);
+
+impl<T: Primitive + Bounds<MIN, MAX>, const MIN: i128, const MAX: i128> Bounded<T, MIN, MAX> {
+    /// The smallest value this bounded integer can contain.
+    pub const MIN_VALUE: T = <T as Bounds<MIN, MAX>>::MIN;
+    /// The largest value that this bounded integer can contain.
+    pub const MAX_VALUE: T = <T as Bounds<MIN, MAX>>::MAX;
+
+    /// The smallest value of the bounded integer.
+    pub const MIN: Self = Self(Self::MIN_VALUE);
+    /// The largest value of the bounded integer.
+    pub const MAX: Self = Self(Self::MAX_VALUE);
+
+    /// Creates a bounded integer without checking the value.
+    ///
+    /// # Safety
+    ///
+    /// The value must not be outside the valid range of values; it must not be less than
+    /// [`MIN_VALUE`](Self::MIN_VALUE) or greater than [`MAX_VALUE`](Self::MAX_VALUE).
+    #[must_use]
+    pub const unsafe fn new_unchecked(n: T) -> Self {
+        Self(n)
+    }
+
+    /// Checks whether the given value is in the range of the bounded integer.
+    #[must_use]
+    #[inline]
+    pub fn in_range(n: T) -> bool {
+        n >= Self::MIN_VALUE && n <= Self::MAX_VALUE
+    }
+
+    /// Creates a bounded integer if the given value is within the range
+    /// [`MIN_VALUE`](Self::MIN_VALUE)..=[`MAX_VALUE`](Self::MAX_VALUE).
+    #[must_use]
+    #[inline]
+    pub fn new(n: T) -> Option<Self> {
+        Self::in_range(n).then_some(Self(n))
+    }
+
+    /// Creates a bounded integer by setting the value to [`MIN_VALUE`](Self::MIN_VALUE) or
+    /// [`MAX_VALUE`](Self::MAX_VALUE) if it is too low or too high respectively.
+    #[must_use]
+    #[inline]
+    pub fn new_saturating(n: T) -> Self {
+        Self(n.clamp(Self::MIN_VALUE, Self::MAX_VALUE))
+    }
+
+    /// Returns the value of the bounded integer as a primitive type.
+    #[must_use]
+    #[inline]
+    pub const fn get(self) -> T {
+        self.0
+    }
+
+    /// Returns a shared reference to the value of the bounded integer.
+    #[must_use]
+    #[inline]
+    pub const fn get_ref(&self) -> &T {
+        &self.0
+    }
+
+    /// Checked integer addition.
+    #[must_use]
+    #[inline]
+    pub fn checked_add(self, rhs: T) -> Option<Self> {
+        self.0.checked_add(rhs).and_then(Self::new)
+    }
+
+    /// Checked integer subtraction.
+    #[must_use]
+    #[inline]
+    pub fn checked_sub(self, rhs: T) -> Option<Self> {
+        self.0.checked_sub(rhs).and_then(Self::new)
+    }
+
+    /// Checked integer multiplication.
+    #[must_use]
+    #[inline]
+    pub fn checked_mul(self, rhs: T) -> Option<Self> {
+        self.0.checked_mul(rhs).and_then(Self::new)
+    }
+
+    /// Checked integer division.
+    #[must_use]
+    #[inline]
+    pub fn checked_div(self, rhs: T) -> Option<Self> {
+        self.0.checked_div(rhs).and_then(Self::new)
+    }
+
+    /// Checked integer remainder.
+    #[must_use]
+    #[inline]
+    pub fn checked_rem(self, rhs: T) -> Option<Self> {
+        self.0.checked_rem(rhs).and_then(Self::new)
+    }
+}
+
+impl<T: Display, const MIN: i128, const MAX: i128> Display for Bounded<T, MIN, MAX> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl<T: PartialEq, const MIN: i128, const MAX: i128> PartialEq<T> for Bounded<T, MIN, MAX> {
+    #[inline]
+    fn eq(&self, other: &T) -> bool {
+        self.0 == *other
+    }
+}
+
+impl<T: PartialOrd, const MIN: i128, const MAX: i128> PartialOrd<T> for Bounded<T, MIN, MAX> {
+    #[inline]
+    fn partial_cmp(&self, other: &T) -> Option<Ordering> {
+        self.0.partial_cmp(other)
+    }
+}
+
+impl<T, const MIN: i128, const MAX: i128> AsRef<T> for Bounded<T, MIN, MAX> {
+    #[inline]
+    fn as_ref(&self) -> &T {
+        &self.0
+    }
+}
+
+// The derived `Hash` hashes only the primitive, so this is consistent with `Borrow`.
+impl<T, const MIN: i128, const MAX: i128> Borrow<T> for Bounded<T, MIN, MAX> {
+    #[inline]
+    fn borrow(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: Primitive + Bounds<MIN, MAX>, const MIN: i128, const MAX: i128> BoundedInteger
+    for Bounded<T, MIN, MAX>
+{
+    type Repr = T;
+
+    const MIN_VALUE: T = Self::MIN_VALUE;
+    const MAX_VALUE: T = Self::MAX_VALUE;
+
+    #[inline]
+    fn new(n: T) -> Option<Self> {
+        Self::new(n)
+    }
+    #[inline]
+    fn new_saturating(n: T) -> Self {
+        Self::new_saturating(n)
+    }
+    #[inline]
+    fn get(self) -> T {
+        self.get()
+    }
+}
+
+macro_rules! impl_from_bounded {
+    ($($ty:ident)*) => { $(
+        impl<const MIN: i128, const MAX: i128> From<Bounded<$ty, MIN, MAX>> for $ty {
+            #[inline]
+            fn from(bounded: Bounded<$ty, MIN, MAX>) -> Self {
+                bounded.0
+            }
+        }
+    )* };
+}
+
+impl_from_bounded!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+
+#[cfg(test)]
+mod tests {
+    use super::Bounded;
+    use crate::{BoundedI16, BoundedInteger, BoundedU8};
+
+    #[test]
+    fn behaves_like_bounded_u8() {
+        type Generic = Bounded<u8, 0, 7>;
+        type Specific = BoundedU8<0, 7>;
+
+        assert_eq!(Generic::MIN_VALUE, Specific::MIN_VALUE);
+        assert_eq!(Generic::MAX_VALUE, Specific::MAX_VALUE);
+        assert_eq!(Generic::MIN.get(), Specific::MIN.get());
+        assert_eq!(Generic::MAX.get(), Specific::MAX.get());
+        for n in 0..=u8::MAX {
+            assert_eq!(
+                Generic::new(n).map(Generic::get),
+                Specific::new(n).map(Specific::get)
+            );
+            assert_eq!(
+                Generic::new_saturating(n).get(),
+                Specific::new_saturating(n).get()
+            );
+            assert_eq!(Generic::in_range(n), Specific::in_range(n));
+        }
+
+        let generic = Generic::new(5).unwrap();
+        let specific = Specific::new(5).unwrap();
+        for rhs in 0..=u8::MAX {
+            let get = |n: Option<Specific>| n.map(Specific::get);
+            assert_eq!(
+                generic.checked_add(rhs).map(Generic::get),
+                get(specific.checked_add(rhs))
+            );
+            assert_eq!(
+                generic.checked_sub(rhs).map(Generic::get),
+                get(specific.checked_sub(rhs))
+            );
+            assert_eq!(
+                generic.checked_mul(rhs).map(Generic::get),
+                get(specific.checked_mul(rhs))
+            );
+            assert_eq!(
+                generic.checked_div(rhs).map(Generic::get),
+                get(specific.checked_div(rhs))
+            );
+            assert_eq!(
+                generic.checked_rem(rhs).map(Generic::get),
+                get(specific.checked_rem(rhs))
+            );
+        }
+
+        assert_eq!(generic, 5);
+        assert!(generic < 6);
+        assert!(Generic::MIN < generic);
+        assert_eq!(u8::from(generic), 5);
+        assert_eq!(generic.saturating_cast_to::<Specific>(), specific);
+        assert_eq!(specific.saturating_cast_to::<Generic>(), generic);
+
+        extern crate std;
+        use std::format;
+        assert_eq!(format!("{generic}"), format!("{specific}"));
+    }
+
+    #[test]
+    fn signed() {
+        type Generic = Bounded<i16, -300, 300>;
+        type Specific = BoundedI16<-300, 300>;
+
+        assert_eq!(Generic::MIN_VALUE, -300);
+        assert_eq!(Generic::new(-301), None);
+        assert_eq!(Generic::new_saturating(i16::MIN), Generic::MIN);
+        let n = Generic::new(-7).unwrap();
+        assert_eq!(n.saturating_cast_to::<Specific>(), -7);
+        assert_eq!(n.checked_mul(50), None);
+    }
+
+    #[test]
+    fn wide_bounds() {
+        type Unsigned = Bounded<u128, 0, { i128::MAX }>;
+        assert_eq!(Unsigned::MAX_VALUE, i128::MAX as u128);
+        assert_eq!(Unsigned::new(u128::MAX), None);
+
+        type Signed = Bounded<i128, { i128::MIN }, { i128::MAX }>;
+        assert_eq!(Signed::new(i128::MIN), Some(Signed::MIN));
+    }
+}
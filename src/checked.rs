@@ -0,0 +1,76 @@
+use crate::{BoundedInteger, Primitive};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Rem, RemAssign, Sub, SubAssign};
+
+/// A bounded integer that becomes poisoned once an arithmetic operation on it goes out of range.
+///
+/// Every operation after that short-circuits, so a long chain of arithmetic can be checked once at
+/// the end with [`into_inner`](Self::into_inner) instead of after each step.
+///
+/// # Examples
+///
+/// ```
+#[cfg_attr(feature = "step_trait", doc = "# #![feature(step_trait)]")]
+#[cfg_attr(not(feature = "types"), doc = "# #[cfg(any())] {")]
+/// use bounded_integer::{BoundedU8, Checked};
+///
+/// let n = BoundedU8::<0, 10>::new(5).unwrap();
+/// assert_eq!((Checked::new(n) + 3 - 2).into_inner(), BoundedU8::new(6));
+/// // `5 + 8` is out of range, so the result stays poisoned even though `13 - 9` is in range.
+/// assert_eq!((Checked::new(n) + 8 - 9).into_inner(), None);
+#[cfg_attr(not(feature = "types"), doc = "# }")]
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Checked<B>(Option<B>);
+
+impl<B: BoundedInteger> Checked<B> {
+    /// Wraps a bounded integer that is not poisoned.
+    #[must_use]
+    pub const fn new(value: B) -> Self {
+        Self(Some(value))
+    }
+
+    /// Returns the bounded integer, or [`None`] if an operation went out of range.
+    #[must_use]
+    pub fn into_inner(self) -> Option<B> {
+        self.0
+    }
+
+    /// Returns whether an operation went out of range.
+    #[must_use]
+    pub fn is_poisoned(&self) -> bool {
+        self.0.is_none()
+    }
+}
+
+impl<B: BoundedInteger> From<B> for Checked<B> {
+    fn from(value: B) -> Self {
+        Self::new(value)
+    }
+}
+
+macro_rules! impl_checked_op {
+    ($($op:ident::$method:ident/$op_assign:ident::$method_assign:ident => $checked:ident,)*) => { $(
+        impl<B: BoundedInteger> $op<B::Repr> for Checked<B> {
+            type Output = Self;
+            #[inline]
+            fn $method(self, rhs: B::Repr) -> Self::Output {
+                Self(self.0.and_then(|value| value.get().$checked(rhs)).and_then(B::new))
+            }
+        }
+
+        impl<B: BoundedInteger> $op_assign<B::Repr> for Checked<B> {
+            #[inline]
+            fn $method_assign(&mut self, rhs: B::Repr) {
+                *self = <Self as $op<B::Repr>>::$method(*self, rhs);
+            }
+        }
+    )* };
+}
+
+impl_checked_op! {
+    Add::add/AddAssign::add_assign => checked_add,
+    Sub::sub/SubAssign::sub_assign => checked_sub,
+    Mul::mul/MulAssign::mul_assign => checked_mul,
+    Div::div/DivAssign::div_assign => checked_div,
+    Rem::rem/RemAssign::rem_assign => checked_rem,
+}
@@ -0,0 +1,324 @@
+//! Tests for code that must not compile.
+
+/// Bitwise not is not implemented for ranges narrower than the underlying type.
+///
+/// ```compile_fail
+#[cfg_attr(feature = "step_trait", doc = "# #![feature(step_trait)]")]
+/// bounded_integer::bounded_integer! {
+///     struct S { 0..255 }
+/// }
+/// let _ = !S::MIN;
+/// ```
+#[cfg(feature = "macro")]
+struct MacroNotNarrowRange;
+
+/// Ranges containing zero can't be converted to non-zero integers.
+///
+/// ```compile_fail
+#[cfg_attr(feature = "step_trait", doc = "# #![feature(step_trait)]")]
+/// bounded_integer::bounded_integer! {
+///     struct S { 0..10 }
+/// }
+/// let _ = core::num::NonZeroU8::from(S::MAX);
+/// ```
+#[cfg(feature = "macro")]
+struct MacroNonZeroWithZero;
+
+/// Alignments must be powers of two.
+///
+/// ```compile_fail
+#[cfg_attr(feature = "step_trait", doc = "# #![feature(step_trait)]")]
+/// bounded_integer::bounded_integer! {
+///     #[repr(u8, align(3))]
+///     struct S { 0..10 }
+/// }
+/// ```
+#[cfg(feature = "macro")]
+struct NonPowerOfTwoAlign;
+
+/// The C representation can't be given twice.
+///
+/// ```compile_fail
+#[cfg_attr(feature = "step_trait", doc = "# #![feature(step_trait)]")]
+/// bounded_integer::bounded_integer! {
+///     #[repr(C, u8, C)]
+///     enum S { 0..10 }
+/// }
+/// ```
+#[cfg(feature = "macro")]
+struct DuplicateReprC;
+
+/// Exclusive ranges must not be empty.
+///
+/// ```compile_fail
+#[cfg_attr(feature = "step_trait", doc = "# #![feature(step_trait)]")]
+/// bounded_integer::bounded_integer! {
+///     struct S { 0..0 }
+/// }
+/// ```
+#[cfg(feature = "macro")]
+struct EmptyExclusiveRange;
+
+/// Ranges given by a start and length must not be empty.
+///
+/// ```compile_fail
+#[cfg_attr(feature = "step_trait", doc = "# #![feature(step_trait)]")]
+/// bounded_integer::bounded_integer! {
+///     struct S { start: 3, len: 0 }
+/// }
+/// ```
+#[cfg(feature = "macro")]
+struct ZeroLength;
+
+/// The range must fit in an explicitly given repr.
+///
+/// ```compile_fail
+#[cfg_attr(feature = "step_trait", doc = "# #![feature(step_trait)]")]
+/// bounded_integer::bounded_integer! {
+///     #[repr(u8)]
+///     struct S { 0..=1000 }
+/// }
+/// ```
+#[cfg(feature = "macro")]
+struct ReprTooNarrow;
+
+/// Every discriminant of an enum must fit in its explicitly given repr.
+///
+/// ```compile_fail
+#[cfg_attr(feature = "step_trait", doc = "# #![feature(step_trait)]")]
+/// bounded_integer::bounded_integer! {
+///     #[repr(i8)]
+///     enum S { 100..=128 }
+/// }
+/// ```
+#[cfg(feature = "macro")]
+struct EnumReprTooNarrow;
+
+/// Moduli used with `reduce` must be positive.
+///
+/// ```compile_fail
+#[cfg_attr(feature = "step_trait", doc = "# #![feature(step_trait)]")]
+/// bounded_integer::bounded_integer! {
+///     struct S { 0..5 }
+/// }
+/// let _ = S::reduce(7, S::MAX);
+/// ```
+#[cfg(feature = "macro")]
+struct MacroNonPositiveModulus;
+
+/// `assert_in_range!` fails to compile for values out of range.
+///
+/// ```compile_fail
+#[cfg_attr(feature = "step_trait", doc = "# #![feature(step_trait)]")]
+/// bounded_integer::bounded_integer! {
+///     struct S { 0..5 }
+/// }
+/// const N: S = bounded_integer::assert_in_range!(S, 5);
+/// ```
+#[cfg(feature = "macro")]
+struct MacroAssertInRange;
+
+/// Integer literals must not have a suffix other than a primitive integer type.
+///
+/// ```compile_fail
+#[cfg_attr(feature = "step_trait", doc = "# #![feature(step_trait)]")]
+/// bounded_integer::bounded_integer! {
+///     struct S { 0..12abc }
+/// }
+/// ```
+#[cfg(feature = "macro")]
+struct MalformedLiteral;
+
+/// Casts are only provided to types that can hold every value in the range.
+///
+/// ```compile_fail
+#[cfg_attr(feature = "step_trait", doc = "# #![feature(step_trait)]")]
+/// bounded_integer::bounded_integer! {
+///     struct S { -1..=255 }
+/// }
+/// let _ = S::MAX.as_u8();
+/// ```
+#[cfg(feature = "macro")]
+struct LossyMacroCast;
+
+/// Types with `#[serde(serialize_only)]` don't implement `Deserialize`.
+///
+/// ```compile_fail
+#[cfg_attr(feature = "step_trait", doc = "# #![feature(step_trait)]")]
+#[cfg_attr(not(feature = "serde"), doc = "# compile_error!(\"\");")]
+/// bounded_integer::bounded_integer! {
+///     #[serde(serialize_only)]
+///     struct S { 0..10 }
+/// }
+/// let _: S = serde_json::from_str("5").unwrap();
+/// ```
+#[cfg(feature = "macro")]
+struct SerdeSerializeOnly;
+
+/// Types with `#[serde(deserialize_only)]` don't implement `Serialize`.
+///
+/// ```compile_fail
+#[cfg_attr(feature = "step_trait", doc = "# #![feature(step_trait)]")]
+#[cfg_attr(not(feature = "serde"), doc = "# compile_error!(\"\");")]
+/// bounded_integer::bounded_integer! {
+///     #[serde(deserialize_only)]
+///     struct S { 0..10 }
+/// }
+/// let _ = serde_json::to_string(&S::MIN);
+/// ```
+#[cfg(feature = "macro")]
+struct SerdeDeserializeOnly;
+
+/// A type can't be both `serialize_only` and `deserialize_only`.
+///
+/// ```compile_fail
+#[cfg_attr(feature = "step_trait", doc = "# #![feature(step_trait)]")]
+/// bounded_integer::bounded_integer! {
+///     #[serde(serialize_only, deserialize_only)]
+///     struct S { 0..10 }
+/// }
+/// ```
+#[cfg(feature = "macro")]
+struct SerdeBothDirectionsOnly;
+
+/// Types with `#[no_eq]` can't be compared for equality.
+///
+/// ```compile_fail
+#[cfg_attr(feature = "step_trait", doc = "# #![feature(step_trait)]")]
+/// bounded_integer::bounded_integer! {
+///     #[no_eq]
+///     struct S { 0..10 }
+/// }
+/// let _ = S::MIN == S::MAX;
+/// ```
+/// ```compile_fail
+#[cfg_attr(feature = "step_trait", doc = "# #![feature(step_trait)]")]
+/// bounded_integer::bounded_integer! {
+///     #[no_eq]
+///     struct S { 0..10 }
+/// }
+/// let _ = S::MIN == 0;
+/// ```
+#[cfg(feature = "macro")]
+struct NoEq;
+
+/// Types with `#[no_ord]` can't be ordered.
+///
+/// ```compile_fail
+#[cfg_attr(feature = "step_trait", doc = "# #![feature(step_trait)]")]
+/// bounded_integer::bounded_integer! {
+///     #[no_ord]
+///     enum S { 0..10 }
+/// }
+/// let _ = S::MIN < S::MAX;
+/// ```
+#[cfg(feature = "macro")]
+struct NoOrd;
+
+/// Types with `#[no_copy]` are `Clone` but not `Copy`.
+///
+/// ```compile_fail
+#[cfg_attr(feature = "step_trait", doc = "# #![feature(step_trait)]")]
+/// bounded_integer::bounded_integer! {
+///     #[no_copy]
+///     struct S { 0..10 }
+/// }
+/// fn is_clone<T: Clone>(_: &T) {}
+/// let s = S::MIN;
+/// is_clone(&s);
+/// let _a = s;
+/// let _b = s;
+/// ```
+#[cfg(feature = "macro")]
+struct NoCopy;
+
+/// `char` conversions are only provided for ranges of Unicode scalar values.
+///
+/// ```compile_fail
+#[cfg_attr(feature = "step_trait", doc = "# #![feature(step_trait)]")]
+/// bounded_integer::bounded_integer! {
+///     struct S { 0xD000..0xE000 }
+/// }
+/// let _ = char::from(S::MIN);
+/// ```
+#[cfg(feature = "macro")]
+struct NonScalarChar;
+
+/// Casts are only provided to types that can hold every value of the primitive.
+///
+/// ```compile_fail
+/// let _ = bounded_integer::BoundedI8::<0, 5>::MIN.as_u8();
+/// ```
+#[cfg(feature = "types")]
+struct LossyTypesCast;
+
+/// Bitwise not is not implemented for ranges narrower than the underlying type.
+///
+/// ```compile_fail
+/// let _ = !bounded_integer::BoundedU8::<0, 254>::MIN;
+/// ```
+#[cfg(feature = "types")]
+struct TypesNotNarrowRange;
+
+/// The bounds of the generic bounded integer must fit in its primitive.
+///
+/// ```compile_fail
+/// let _ = bounded_integer::Bounded::<u8, 0, 256>::new(5);
+/// ```
+#[cfg(feature = "types")]
+struct GenericBoundTooWide;
+
+/// The minimum of the generic bounded integer must not be greater than its maximum.
+///
+/// ```compile_fail
+/// let _ = bounded_integer::Bounded::<i8, 5, 0>::MIN;
+/// ```
+#[cfg(feature = "types")]
+struct GenericBoundsReversed;
+
+/// Moduli used with `reduce` must be positive.
+///
+/// ```compile_fail
+/// let modulus = bounded_integer::BoundedI8::<0, 5>::MAX;
+/// let _ = bounded_integer::BoundedI8::reduce(7, modulus);
+/// ```
+#[cfg(feature = "types")]
+struct TypesNonPositiveModulus;
+
+/// The maximum of the result of `rem_euclid_const` must be one less than the modulus.
+///
+/// ```compile_fail
+/// let _ = bounded_integer::BoundedU8::<0, 100>::MAX.rem_euclid_const::<10, 10>();
+/// ```
+#[cfg(feature = "types")]
+struct TypesWrongRemainderMaximum;
+
+/// `INDEX_LEN` can only be used when the number of values fits in a `usize`.
+///
+/// ```compile_fail
+/// let _ = bounded_integer::BoundedU128::<0, { u128::MAX }>::INDEX_LEN;
+/// ```
+#[cfg(feature = "types")]
+struct TypesIndexLenOverflow;
+
+/// The bounds of the result of `signed_diff_bounded` must hold exactly every possible difference.
+///
+/// ```compile_fail
+/// let n = bounded_integer::BoundedU8::<0, 10>::MIN;
+/// let _: bounded_integer::BoundedI16<-10, 11> = n.signed_diff_bounded(n);
+/// ```
+#[cfg(feature = "types")]
+struct TypesWrongSignedDiffBounds;
+
+/// A range given as a constant must not be empty, which is checked once the constant is evaluated.
+///
+/// ```compile_fail
+#[cfg_attr(feature = "step_trait", doc = "# #![feature(step_trait)]")]
+/// const RANGE: core::ops::RangeInclusive<i32> = 10..=-10;
+/// bounded_integer::bounded_integer! {
+///     #[repr(i32)]
+///     struct S { RANGE }
+/// }
+/// ```
+#[cfg(feature = "macro")]
+struct EmptyConstRange;
@@ -35,6 +35,7 @@ bounded_integer! {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use core::cmp::Ordering;
 
     macro_rules! test_range {
         ($fn:ident, $bounded:ident) => {
@@ -56,6 +57,59 @@ mod tests {
         };
     }
 
+    macro_rules! test_range_bounds {
+        ($fn:ident, $bounded:ident) => {
+            #[test]
+            fn $fn() {
+                use core::ops::{Bound, RangeBounds};
+
+                assert_eq!($bounded::RANGE.start_bound(), Bound::Included(&-8));
+                assert_eq!($bounded::RANGE.end_bound(), Bound::Included(&7));
+                for n in -20..20 {
+                    assert_eq!(
+                        RangeBounds::contains(&$bounded::RANGE, &n),
+                        $bounded::in_range(n)
+                    );
+                }
+            }
+        };
+    }
+
+    macro_rules! test_new_const {
+        ($fn:ident, $bounded:ident) => {
+            #[test]
+            fn $fn() {
+                const N: $bounded = $bounded::new_const::<-3>();
+                assert_eq!(N.get(), -3);
+                assert_eq!($bounded::new_const::<-8>(), $bounded::MIN);
+                assert_eq!($bounded::new_const::<7>(), $bounded::MAX);
+            }
+        };
+    }
+
+    macro_rules! test_assert_in_range {
+        ($fn:ident, $bounded:ident) => {
+            #[test]
+            fn $fn() {
+                const N: $bounded = crate::assert_in_range!($bounded, -3);
+                assert_eq!(N.get(), -3);
+                assert_eq!(crate::assert_in_range!($bounded, 7), $bounded::MAX);
+            }
+        };
+    }
+
+    macro_rules! test_as_primitive {
+        ($fn:ident, $bounded:ident) => {
+            #[test]
+            fn $fn() {
+                assert_eq!($bounded::MIN.as_i8(), -8_i8);
+                assert_eq!($bounded::MIN.as_i32(), -8_i32);
+                assert_eq!($bounded::MAX.as_i128(), 7_i128);
+                assert_eq!($bounded::MAX.as_isize(), 7_isize);
+            }
+        };
+    }
+
     macro_rules! test_saturating {
         ($fn:ident, $bounded:ident) => {
             #[test]
@@ -67,6 +121,200 @@ mod tests {
                 assert_eq!($bounded::new_saturating(7).get(), 7);
                 assert_eq!($bounded::new_saturating(8).get(), 7);
                 assert_eq!($bounded::new_saturating(i8::MAX).get(), 7);
+
+                // The absolute value is clamped to the maximum.
+                assert_eq!($bounded::MIN.saturating_abs(), $bounded::MAX);
+                assert_eq!($bounded::new(-3).unwrap().saturating_abs().get(), 3);
+                assert_eq!($bounded::new(5).unwrap().saturating_abs().get(), 5);
+            }
+        };
+    }
+
+    macro_rules! test_increment_decrement {
+        ($fn:ident, $bounded:ident) => {
+            #[test]
+            fn $fn() {
+                assert_eq!($bounded::MAX.checked_increment(), None);
+                assert_eq!($bounded::MIN.checked_decrement(), None);
+                assert_eq!($bounded::MIN.checked_increment().unwrap().get(), -7);
+                assert_eq!($bounded::MAX.checked_decrement().unwrap().get(), 6);
+
+                const WRAPPED: $bounded = $bounded::MAX.cycle_next();
+                assert_eq!(WRAPPED, $bounded::MIN);
+                assert_eq!($bounded::MIN.cycle_prev(), $bounded::MAX);
+                let mut state = $bounded::MIN;
+                for n in -7..8 {
+                    state = state.cycle_next();
+                    assert_eq!(state.get(), n);
+                }
+                assert_eq!(state.cycle_next().cycle_prev(), state);
+            }
+        };
+    }
+
+    macro_rules! test_clamp_repr {
+        ($fn:ident, $bounded:ident) => {
+            #[test]
+            fn $fn() {
+                assert_eq!($bounded::clamp_repr(i8::MIN), -8);
+                assert_eq!($bounded::clamp_repr(-9), -8);
+                assert_eq!($bounded::clamp_repr(-8), -8);
+                assert_eq!($bounded::clamp_repr(0), 0);
+                assert_eq!($bounded::clamp_repr(7), 7);
+                assert_eq!($bounded::clamp_repr(8), 7);
+                assert_eq!($bounded::clamp_repr(i8::MAX), 7);
+            }
+        };
+    }
+
+    macro_rules! test_from_ascii {
+        ($fn:ident, $bounded:ident) => {
+            #[test]
+            fn $fn() {
+                use crate::ParseErrorKind;
+
+                let kind = |bytes| $bounded::from_ascii(bytes).unwrap_err().kind();
+                assert_eq!($bounded::from_ascii(b"5").unwrap().get(), 5);
+                assert_eq!($bounded::from_ascii(b"+7").unwrap(), $bounded::MAX);
+                assert_eq!($bounded::from_ascii(b"-8").unwrap(), $bounded::MIN);
+                assert_eq!($bounded::from_ascii(b"-0").unwrap().get(), 0);
+                assert_eq!($bounded::from_ascii(b"007").unwrap(), $bounded::MAX);
+                assert_eq!(kind(b""), ParseErrorKind::Empty);
+                assert_eq!(kind(b"-"), ParseErrorKind::Empty);
+                assert_eq!(kind(b"1a"), ParseErrorKind::InvalidDigit);
+                assert_eq!(kind(b" 1"), ParseErrorKind::InvalidDigit);
+                assert_eq!(kind(b"\xff"), ParseErrorKind::InvalidDigit);
+                assert_eq!(kind(b"8"), ParseErrorKind::AboveMax);
+                assert_eq!(kind(b"-9"), ParseErrorKind::BelowMin);
+                assert_eq!(
+                    kind(b"99999999999999999999999999999999999999999"),
+                    ParseErrorKind::AboveMax
+                );
+                assert_eq!(
+                    kind(b"-99999999999999999999999999999999999999999"),
+                    ParseErrorKind::BelowMin
+                );
+            }
+        };
+    }
+
+    macro_rules! test_checked_bounded {
+        ($fn:ident, $bounded:ident) => {
+            #[test]
+            fn $fn() {
+                let b = |n| $bounded::new(n).unwrap();
+                assert_eq!(b(-3).checked_add_bounded(b(5)), Some(b(2)));
+                assert_eq!(b(3).checked_add_bounded(b(5)), None);
+                assert_eq!(b(-3).checked_sub_bounded(b(5)), Some(b(-8)));
+                assert_eq!(b(-4).checked_sub_bounded(b(5)), None);
+                assert_eq!(b(-2).checked_mul_bounded(b(3)), Some(b(-6)));
+                assert_eq!(b(-3).checked_mul_bounded(b(3)), None);
+                assert_eq!(b(-6).checked_div_bounded(b(3)), Some(b(-2)));
+                assert_eq!(b(5).checked_div_bounded(b(0)), None);
+                assert_eq!(b(-8).checked_div_bounded(b(-1)), None);
+                const REMAINDER: Option<$bounded> =
+                    $bounded::MAX.checked_rem_bounded($bounded::MIN);
+                assert_eq!(REMAINDER, Some(b(7)));
+                assert_eq!(b(-7).checked_rem_bounded(b(3)), Some(b(-1)));
+                assert_eq!(b(5).checked_rem_bounded(b(0)), None);
+                assert_eq!(b(-8).checked_rem_bounded(b(-1)), Some(b(0)));
+            }
+        };
+    }
+
+    macro_rules! test_wrapping_distance {
+        ($fn:ident, $bounded:ident) => {
+            #[test]
+            fn $fn() {
+                let b = |n| $bounded::new(n).unwrap();
+                assert_eq!(b(-3).wrapping_distance(b(4)), 7_u8);
+                assert_eq!(b(4).wrapping_distance(b(-3)), 9_u8);
+                assert_eq!(b(0).wrapping_distance(b(0)), 0_u8);
+                assert_eq!($bounded::MAX.wrapping_distance($bounded::MIN), 1_u8);
+                assert_eq!($bounded::MIN.wrapping_distance($bounded::MAX), 15_u8);
+            }
+        };
+    }
+
+    macro_rules! test_to_index {
+        ($fn:ident, $bounded:ident) => {
+            #[test]
+            fn $fn() {
+                assert_eq!($bounded::MIN.to_index(), 0);
+                assert_eq!($bounded::new(0).unwrap().to_index(), 8);
+                assert_eq!($bounded::MAX.to_index(), 15);
+
+                let mut counts = [0; $bounded::INDEX_LEN];
+                counts[$bounded::new(-3).unwrap().to_index()] += 1;
+                assert_eq!(counts[5], 1);
+
+                assert_eq!($bounded::INDEX_LEN, 16);
+                let mut values = [0; $bounded::INDEX_LEN];
+                for n in -8..8 {
+                    values[$bounded::new(n).unwrap().to_index()] = n;
+                }
+                assert_eq!(values, core::array::from_fn(|i| i as i8 - 8));
+            }
+        };
+    }
+
+    macro_rules! test_from_index {
+        ($fn:ident, $bounded:ident) => {
+            #[test]
+            fn $fn() {
+                assert_eq!($bounded::from_index(0), Some($bounded::MIN));
+                assert_eq!($bounded::from_index(15), Some($bounded::MAX));
+                assert_eq!($bounded::from_index(16), None);
+                for n in -8..8 {
+                    let n = $bounded::new(n).unwrap();
+                    assert_eq!($bounded::from_index(n.to_index()), Some(n));
+                }
+            }
+        };
+    }
+
+    macro_rules! test_signum {
+        ($fn:ident, $bounded:ident) => {
+            #[test]
+            fn $fn() {
+                assert_eq!($bounded::MIN.signum(), -1);
+                assert_eq!($bounded::new(-1).unwrap().signum(), -1);
+                assert_eq!($bounded::new(0).unwrap().signum(), 0);
+                assert_eq!($bounded::new(1).unwrap().signum(), 1);
+                assert_eq!($bounded::MAX.signum(), 1);
+            }
+        };
+    }
+
+    macro_rules! test_ilog {
+        ($fn:ident, $bounded:ident) => {
+            #[test]
+            fn $fn() {
+                for n in 1..8 {
+                    let b = $bounded::new(n).unwrap();
+                    assert_eq!(b.checked_ilog2(), Some(n.ilog2()));
+                    assert_eq!(b.checked_ilog10(), Some(n.ilog10()));
+                    assert_eq!(b.checked_ilog(3), Some(n.ilog(3)));
+                }
+                assert_eq!($bounded::new(0).unwrap().checked_ilog2(), None);
+                assert_eq!($bounded::MIN.checked_ilog2(), None);
+                assert_eq!($bounded::MIN.checked_ilog10(), None);
+            }
+        };
+    }
+
+    macro_rules! test_to_string {
+        ($fn:ident, $bounded:ident) => {
+            #[test]
+            #[cfg(feature = "alloc")]
+            fn $fn() {
+                extern crate std;
+                use std::format;
+
+                for n in -8..8 {
+                    let b = $bounded::new(n).unwrap();
+                    assert_eq!(b.to_string(), format!("{}", b));
+                }
             }
         };
     }
@@ -85,11 +333,29 @@ mod tests {
                 assert_eq!(($bounded::new(-2).unwrap() * 3).get(), -6);
                 assert_eq!(($bounded::new(7).unwrap() / 3).get(), 2);
                 assert_eq!(($bounded::new(7).unwrap() % 3).get(), 1);
+                assert_eq!($bounded::new(-3).unwrap().checked_not().unwrap().get(), 2);
                 assert_eq!(-2 + $bounded::new(-8).unwrap(), -10);
             }
         };
     }
 
+    macro_rules! test_borrow_lookup {
+        ($fn:ident, $bounded:ident) => {
+            #[test]
+            fn $fn() {
+                extern crate std;
+                use std::collections::HashMap;
+
+                let mut map = HashMap::new();
+                map.insert($bounded::new(-5).unwrap(), "minus five");
+                map.insert($bounded::new(5).unwrap(), "five");
+                assert_eq!(map.get(&-5), Some(&"minus five"));
+                assert_eq!(map.get(&5), Some(&"five"));
+                assert_eq!(map.get(&6), None);
+            }
+        };
+    }
+
     macro_rules! test_iter {
         ($fn:ident, $bounded:ident) => {
             #[test]
@@ -111,45 +377,1820 @@ mod tests {
         };
     }
 
+    macro_rules! test_get_ref {
+        ($fn:ident, $bounded:ident) => {
+            #[test]
+            fn $fn() {
+                const MAX: &i8 = $bounded::MAX.get_ref();
+                assert_eq!(*MAX, 7);
+
+                for n in -8..8 {
+                    let value = $bounded::new(n).unwrap();
+                    let reference = value.get_ref();
+                    assert_eq!(*reference, value.get());
+                    assert!(core::ptr::eq(
+                        reference as *const i8,
+                        &value as *const $bounded as *const i8
+                    ));
+                }
+
+                // SAFETY: `get_unchecked_ref` has no requirements beyond those of `get_ref`.
+                const MIN: &i8 = unsafe { $bounded::MIN.get_unchecked_ref() };
+                assert_eq!(*MIN, -8);
+                let value = $bounded::new(3).unwrap();
+                // SAFETY: As above.
+                let reference = unsafe { value.get_unchecked_ref() };
+                assert!(core::ptr::eq(reference, value.get_ref()));
+            }
+        };
+    }
+
+    macro_rules! test_cmp_value {
+        ($fn:ident, $bounded:ident) => {
+            #[test]
+            fn $fn() {
+                const SIGN: i8 = match $bounded::MIN.cmp_value(0) {
+                    Ordering::Less => -1,
+                    Ordering::Equal => 0,
+                    Ordering::Greater => 1,
+                };
+                assert_eq!(SIGN, -1);
+
+                for n in -8..8 {
+                    for v in -10..10 {
+                        assert_eq!($bounded::new(n).unwrap().cmp_value(v), n.cmp(&v));
+                    }
+                }
+            }
+        };
+    }
+
+    macro_rules! test_try_sum {
+        ($fn:ident, $bounded:ident) => {
+            #[test]
+            fn $fn() {
+                let b = |n| $bounded::new(n).unwrap();
+                assert_eq!($bounded::try_sum([b(3), b(-5), b(7)]), Some(b(5)));
+                assert_eq!($bounded::try_sum(core::iter::empty()), Some(b(0)));
+                // The total is in range, but the partial sum 7 + 1 is not.
+                assert_eq!($bounded::try_sum([b(7), b(1), b(-8)]), None);
+                assert_eq!($bounded::try_sum([b(-8), b(-1)]), None);
+            }
+        };
+    }
+
+    macro_rules! test_min_max_with {
+        ($fn:ident, $bounded:ident) => {
+            #[test]
+            fn $fn() {
+                const LOW: $bounded = $bounded::MAX.min_with(-3);
+                assert_eq!(LOW.get(), -3);
+
+                let b = |n| $bounded::new(n).unwrap();
+                assert_eq!(b(2).max_with(5), b(5));
+                assert_eq!(b(2).max_with(-5), b(2));
+                // The value would be above the maximum, so it is clamped.
+                assert_eq!(b(2).max_with(100), $bounded::MAX);
+                assert_eq!(b(2).min_with(-5), b(-5));
+                assert_eq!(b(2).min_with(5), b(2));
+                assert_eq!(b(2).min_with(-100), $bounded::MIN);
+            }
+        };
+    }
+
+    macro_rules! test_midpoint_with {
+        ($fn:ident, $bounded:ident) => {
+            #[test]
+            fn $fn() {
+                const MID: $bounded = $bounded::MIN.midpoint_with($bounded::MAX);
+                assert_eq!(MID.get(), -1);
+                assert_eq!($bounded::MAX.midpoint_with($bounded::MIN).get(), -1);
+
+                let b = |n| $bounded::new(n).unwrap();
+                assert_eq!(b(-3).midpoint_with(b(5)), b(1));
+                assert_eq!(b(-3).midpoint_with(b(4)), b(0));
+                assert_eq!(b(-4).midpoint_with(b(-3)), b(-4));
+                assert_eq!(b(7).midpoint_with(b(7)), b(7));
+            }
+        };
+    }
+
+    macro_rules! test_parity {
+        ($fn:ident, $bounded:ident) => {
+            #[test]
+            fn $fn() {
+                const EVEN: bool = $bounded::MIN.is_even();
+                assert!(EVEN);
+                for n in -8..8 {
+                    let b = $bounded::new(n).unwrap();
+                    assert_eq!(b.is_even(), n % 2 == 0);
+                    assert_eq!(b.is_odd(), n % 2 != 0);
+                }
+                assert!($bounded::new(-3).unwrap().is_odd());
+                assert!($bounded::new(-2).unwrap().is_even());
+            }
+        };
+    }
+
+    macro_rules! test_new_array {
+        ($fn:ident, $bounded:ident) => {
+            #[test]
+            fn $fn() {
+                let values = $bounded::new_array([-8, 0, 7]).unwrap();
+                assert_eq!(
+                    values,
+                    [$bounded::MIN, $bounded::new(0).unwrap(), $bounded::MAX]
+                );
+                assert_eq!($bounded::new_array([-8, 8, 0]), None);
+                assert_eq!($bounded::new_array([-9]), None);
+            }
+        };
+    }
+
+    macro_rules! test_slices {
+        ($fn:ident, $bounded:ident) => {
+            #[test]
+            fn $fn() {
+                let valid = [-8, 0, 7, 3];
+                assert_eq!($bounded::validate_slice(&valid), Ok(()));
+                let bounded = $bounded::from_slice(&valid).unwrap();
+                assert_eq!(bounded, valid);
+                assert!(core::ptr::eq(bounded.as_ptr().cast::<i8>(), valid.as_ptr()));
+
+                let invalid = [0, 1, -9, 8];
+                assert_eq!($bounded::validate_slice(&invalid), Err((2, -9)));
+                assert_eq!($bounded::from_slice(&invalid), Err((2, -9)));
+                // SAFETY: The first two values are in range.
+                let prefix = unsafe { $bounded::from_slice_unchecked(&invalid[..2]) };
+                assert_eq!(prefix, [0, 1]);
+            }
+        };
+    }
+
+    macro_rules! test_new_or {
+        ($fn:ident, $bounded:ident) => {
+            #[test]
+            fn $fn() {
+                const FALLBACK: $bounded = $bounded::new_or(8, $bounded::MIN);
+                assert_eq!(FALLBACK, $bounded::MIN);
+                assert_eq!($bounded::new_or(-9, $bounded::MAX), $bounded::MAX);
+                assert_eq!($bounded::new_or(3, $bounded::MIN).get(), 3);
+            }
+        };
+    }
+
+    macro_rules! test_checked_mul_div {
+        ($fn:ident, $bounded:ident) => {
+            #[test]
+            fn $fn() {
+                let b = |n| $bounded::new(n).unwrap();
+                // `7 * 100` overflows an `i8`, but `700 / 120` does not.
+                const SCALED: Option<$bounded> = $bounded::MAX.checked_mul_div(100, 120);
+                assert_eq!(SCALED, Some(b(5)));
+                assert_eq!(b(-8).checked_mul_div(-128, 127), None);
+                assert_eq!(b(-8).checked_mul_div(127, 127), Some(b(-8)));
+                assert_eq!(b(3).checked_mul_div(1, 0), None);
+            }
+        };
+    }
+
+    macro_rules! test_widening_mul {
+        ($fn:ident, $bounded:ident) => {
+            #[test]
+            fn $fn() {
+                const SQUARE: i16 = $bounded::MIN.widening_mul($bounded::MIN);
+                assert_eq!(SQUARE, 64);
+                for a in -8..8 {
+                    for b in -8..8 {
+                        let product = $bounded::new(a)
+                            .unwrap()
+                            .widening_mul($bounded::new(b).unwrap());
+                        assert_eq!(product, i16::from(a) * i16::from(b));
+                    }
+                }
+            }
+        };
+    }
+
+    macro_rules! test_fold_values {
+        ($fn:ident, $bounded:ident) => {
+            #[test]
+            fn $fn() {
+                let sum = $bounded::fold_values(0_i32, |sum, n| sum + i32::from(n.get()));
+                // The sum of an arithmetic series is the number of terms times their mean.
+                assert_eq!(sum, 16 * (-8 + 7) / 2);
+                let mut values = [0; 16];
+                $bounded::fold_values(0, |i, n| {
+                    values[i] = n.get();
+                    i + 1
+                });
+                assert_eq!(values, core::array::from_fn(|i| i as i8 - 8));
+            }
+        };
+    }
+
+    macro_rules! test_layout {
+        ($fn:ident, $bounded:ident) => {
+            #[test]
+            fn $fn() {
+                use core::mem::{align_of, size_of};
+
+                assert_eq!(size_of::<$bounded>(), size_of::<i8>());
+                assert_eq!(align_of::<$bounded>(), align_of::<i8>());
+                assert_eq!(unsafe { $bounded::from_repr_unchecked(-3) }.get(), -3);
+            }
+        };
+    }
+
     test_range!(test_struct_range, BoundedStruct);
+    test_new_const!(test_struct_new_const, BoundedStruct);
+    test_as_primitive!(test_struct_as_primitive, BoundedStruct);
     test_saturating!(test_struct_saturating, BoundedStruct);
+    test_increment_decrement!(test_struct_increment_decrement, BoundedStruct);
+    test_checked_bounded!(test_struct_checked_bounded, BoundedStruct);
+    test_clamp_repr!(test_struct_clamp_repr, BoundedStruct);
+    test_from_ascii!(test_struct_from_ascii, BoundedStruct);
+    test_wrapping_distance!(test_struct_wrapping_distance, BoundedStruct);
+    test_to_index!(test_struct_to_index, BoundedStruct);
+    test_from_index!(test_struct_from_index, BoundedStruct);
+    test_ilog!(test_struct_ilog, BoundedStruct);
+    test_signum!(test_struct_signum, BoundedStruct);
+    test_to_string!(test_struct_to_string, BoundedStruct);
+    test_assert_in_range!(test_struct_assert_in_range, BoundedStruct);
     test_arithmetic!(test_struct_arithmetic, BoundedStruct);
+    test_borrow_lookup!(test_struct_borrow_lookup, BoundedStruct);
     test_iter!(test_struct_iter, BoundedStruct);
+    test_get_ref!(test_struct_get_ref, BoundedStruct);
+    test_cmp_value!(test_struct_cmp_value, BoundedStruct);
+    test_range_bounds!(test_struct_range_bounds, BoundedStruct);
+    test_try_sum!(test_struct_try_sum, BoundedStruct);
+    test_min_max_with!(test_struct_min_max_with, BoundedStruct);
+    test_midpoint_with!(test_struct_midpoint_with, BoundedStruct);
+    test_parity!(test_struct_parity, BoundedStruct);
+    test_new_array!(test_struct_new_array, BoundedStruct);
+    test_new_or!(test_struct_new_or, BoundedStruct);
+    test_slices!(test_struct_slices, BoundedStruct);
+    test_checked_mul_div!(test_struct_checked_mul_div, BoundedStruct);
+    test_widening_mul!(test_struct_widening_mul, BoundedStruct);
+    test_fold_values!(test_struct_fold_values, BoundedStruct);
+    test_layout!(test_struct_layout, BoundedStruct);
 
     test_range!(test_enum_range, BoundedEnum);
+    test_new_const!(test_enum_new_const, BoundedEnum);
+    test_as_primitive!(test_enum_as_primitive, BoundedEnum);
     test_saturating!(test_enum_saturating, BoundedEnum);
+    test_increment_decrement!(test_enum_increment_decrement, BoundedEnum);
+    test_checked_bounded!(test_enum_checked_bounded, BoundedEnum);
+    test_clamp_repr!(test_enum_clamp_repr, BoundedEnum);
+    test_from_ascii!(test_enum_from_ascii, BoundedEnum);
+    test_wrapping_distance!(test_enum_wrapping_distance, BoundedEnum);
+    test_to_index!(test_enum_to_index, BoundedEnum);
+    test_from_index!(test_enum_from_index, BoundedEnum);
+    test_ilog!(test_enum_ilog, BoundedEnum);
+    test_signum!(test_enum_signum, BoundedEnum);
+    test_to_string!(test_enum_to_string, BoundedEnum);
+    test_assert_in_range!(test_enum_assert_in_range, BoundedEnum);
     test_arithmetic!(test_enum_arithmetic, BoundedEnum);
+    test_borrow_lookup!(test_enum_borrow_lookup, BoundedEnum);
     test_iter!(test_enum_iter, BoundedEnum);
+    test_get_ref!(test_enum_get_ref, BoundedEnum);
+    test_cmp_value!(test_enum_cmp_value, BoundedEnum);
+    test_range_bounds!(test_enum_range_bounds, BoundedEnum);
+    test_try_sum!(test_enum_try_sum, BoundedEnum);
+    test_min_max_with!(test_enum_min_max_with, BoundedEnum);
+    test_midpoint_with!(test_enum_midpoint_with, BoundedEnum);
+    test_parity!(test_enum_parity, BoundedEnum);
+    test_new_array!(test_enum_new_array, BoundedEnum);
+    test_new_or!(test_enum_new_or, BoundedEnum);
+    test_slices!(test_enum_slices, BoundedEnum);
+    test_checked_mul_div!(test_enum_checked_mul_div, BoundedEnum);
+    test_widening_mul!(test_enum_widening_mul, BoundedEnum);
+    test_fold_values!(test_enum_fold_values, BoundedEnum);
+    test_layout!(test_enum_layout, BoundedEnum);
 
-    #[allow(unused_imports)]
-    mod all_below_zero {
-        use super::bounded_integer;
+    #[test]
+    fn enum_variants() {
+        assert_eq!(BoundedEnum::VARIANTS.len(), 16);
+        assert_eq!(BoundedEnum::VARIANTS[0], BoundedEnum::MIN);
+        assert_eq!(BoundedEnum::VARIANTS[15], BoundedEnum::MAX);
+        for (i, variant) in BoundedEnum::VARIANTS.iter().enumerate() {
+            assert_eq!(variant.get(), i as i8 - 8);
+        }
+    }
+
+    #[test]
+    fn enum_sorted_variants() {
+        const SORTED: [BoundedEnum; 16] = BoundedEnum::sorted_variants();
+        assert!(SORTED.windows(2).all(|pair| pair[0].get() < pair[1].get()));
+        assert_eq!(SORTED, BoundedEnum::VARIANTS);
+    }
+
+    #[test]
+    fn local_to_function() {
         bounded_integer! {
-            struct Struct { -400..=-203 }
+            #[in_module(local)]
+            pub struct Local { 100..110 }
         }
         bounded_integer! {
-            enum Enum { -500..=-483 }
+            #[no_copy]
+            enum LocalEnum { -3..3 }
         }
+
+        let n = local::Local::new(103).unwrap();
+        assert_eq!(n + 1, 104);
+        assert_eq!(local::Local::MAX, 109);
+        assert_eq!(LocalEnum::MIN.get(), -3);
+        assert_eq!(LocalEnum::new(2), Some(LocalEnum::P2));
     }
 
-    mod correct_reprs {
-        use super::bounded_integer;
-        bounded_integer! {
-            struct ByteStruct { 0..256 }
+    #[test]
+    fn primitive_operators() {
+        let s = BoundedStruct::new(3).unwrap();
+        assert_eq!(s + 4, 7);
+        assert_eq!(s - 10, -7);
+        assert_eq!(s * 2, 6);
+        assert_eq!(s / 2, 1);
+        assert_eq!(s % 2, 1);
+        let e = BoundedEnum::new(-3).unwrap();
+        assert_eq!(e + 10, BoundedEnum::P7);
+        assert_eq!(e * -2, BoundedEnum::P6);
+    }
+
+    #[test]
+    #[should_panic = "Attempted to add out of range"]
+    fn struct_add_out_of_range() {
+        let _ = BoundedStruct::MAX + 1;
+    }
+
+    #[test]
+    #[should_panic = "Attempted to multiply out of range"]
+    fn enum_mul_out_of_range() {
+        let _ = BoundedEnum::MIN * 2;
+    }
+
+    #[test]
+    fn enum_new_nearest() {
+        const NEAREST: BoundedEnum = BoundedEnum::new_nearest(100);
+        assert_eq!(NEAREST, BoundedEnum::MAX);
+        assert_eq!(BoundedEnum::new_nearest(i8::MIN), BoundedEnum::MIN);
+        assert_eq!(BoundedEnum::new_nearest(-9), BoundedEnum::MIN);
+        assert_eq!(BoundedEnum::new_nearest(8), BoundedEnum::MAX);
+        // The variants have no gaps, so every value in the range is its own nearest variant.
+        for n in -8..8 {
+            assert_eq!(BoundedEnum::new_nearest(n).get(), n);
         }
-        const _: u8 = ByteStruct::MIN_VALUE;
-        bounded_integer! {
-            enum ByteEnum { 0..256 }
+    }
+
+    #[test]
+    fn hash_seed() {
+        const SEED: u64 = BoundedStruct::MIN.hash_seed();
+        assert_eq!(SEED, BoundedStruct::MIN.hash_seed());
+        let seeds: [u64; 16] = core::array::from_fn(|i| BoundedEnum::VARIANTS[i].hash_seed());
+        for (i, seed) in seeds.iter().enumerate() {
+            let value = BoundedStruct::new(i as i8 - 8).unwrap();
+            assert_eq!(*seed, value.hash_seed());
+            assert!(seeds[..i].iter().all(|other| other != seed));
         }
-        const _: u8 = ByteEnum::MIN_VALUE;
+    }
+
+    #[test]
+    fn enum_variants_iter() {
+        assert_eq!(BoundedEnum::variants().len(), 16);
+        assert!(BoundedEnum::variants().eq(BoundedEnum::VARIANTS));
+        assert!(BoundedEnum::variants().map(BoundedEnum::get).eq(-8..8));
+
+        let mut variants = BoundedEnum::variants();
+        variants.next();
+        assert_eq!(variants.len(), 15);
+    }
+
+    #[test]
+    fn saturating_cast_to() {
+        use crate::BoundedInteger;
 
         bounded_integer! {
-            struct SignedByteStruct { -128..128 }
+            struct Narrow { 0..=3 }
         }
-        const _: i8 = SignedByteStruct::MIN_VALUE;
-        bounded_integer! {
+
+        assert_eq!(
+            BoundedStruct::MIN.saturating_cast_to::<BoundedEnum>(),
+            BoundedEnum::MIN
+        );
+        assert_eq!(
+            BoundedStruct::MIN.saturating_cast_to::<Narrow>(),
+            Narrow::MIN
+        );
+        assert_eq!(BoundedEnum::MAX.saturating_cast_to::<Narrow>(), Narrow::MAX);
+        let two = BoundedStruct::new(2).unwrap();
+        assert_eq!(two.saturating_cast_to::<Narrow>(), 2);
+    }
+
+    #[test]
+    fn enum_variant_names() {
+        assert_eq!(BoundedEnum::MIN.variant_name(), "N8");
+        assert_eq!(BoundedEnum::new(-1).unwrap().variant_name(), "N1");
+        assert_eq!(BoundedEnum::new(0).unwrap().variant_name(), "Z");
+        assert_eq!(BoundedEnum::MAX.variant_name(), "P7");
+        let names = [
+            "N8", "N7", "N6", "N5", "N4", "N3", "N2", "N1", "Z", "P1", "P2", "P3", "P4", "P5",
+            "P6", "P7",
+        ];
+        for (variant, name) in BoundedEnum::VARIANTS.iter().zip(names) {
+            assert_eq!(variant.variant_name(), name);
+        }
+    }
+
+    #[test]
+    fn enum_niche() {
+        use core::mem::size_of;
+
+        assert_eq!(size_of::<Option<BoundedEnum>>(), size_of::<BoundedEnum>());
+        assert_eq!(
+            size_of::<Option<BoundedStruct>>(),
+            2 * size_of::<BoundedStruct>()
+        );
+    }
+
+    #[allow(unused_imports)]
+    mod all_below_zero {
+        use super::bounded_integer;
+        bounded_integer! {
+            struct Struct { -400..=-203 }
+        }
+        bounded_integer! {
+            enum Enum { -500..=-483 }
+        }
+    }
+
+    mod large_enum {
+        use super::bounded_integer;
+        bounded_integer! {
+            enum Large { 1000..=1255 }
+        }
+        bounded_integer! {
+            #[no_copy]
+            enum LargeNoCopy { -100..100 }
+        }
+
+        #[test]
+        fn membership() {
+            assert_eq!(Large::new(999), None);
+            assert_eq!(Large::new(1000), Some(Large::P1000));
+            assert_eq!(Large::new(1128), Some(Large::P1128));
+            assert_eq!(Large::new(1255), Some(Large::P1255));
+            assert_eq!(Large::new(1256), None);
+
+            assert_eq!(Large::new_saturating(0), Large::P1000);
+            assert_eq!(Large::new_saturating(1200), Large::P1200);
+            assert_eq!(Large::new_saturating(u16::MAX), Large::P1255);
+        }
+
+        #[test]
+        fn checked_not() {
+            assert_eq!(Large::P1000.checked_not(), None);
+        }
+
+        #[test]
+        fn variants() {
+            assert_eq!(Large::variants().len(), 256);
+            assert_eq!(Large::variants().last(), Some(Large::P1255));
+            assert_eq!(Large::VARIANTS[128], Large::P1128);
+            assert_eq!(Large::P1128.variant_name(), "P1128");
+            assert_eq!(Large::new_nearest(2000), Large::P1255);
+            assert_eq!(LargeNoCopy::variants().next(), Some(LargeNoCopy::N100));
+        }
+
+        #[test]
+        fn enum_api() {
+            extern crate std;
+            use core::mem::size_of;
+            use std::format;
+
+            assert_eq!(size_of::<Large>(), size_of::<u16>());
+            assert_eq!(format!("{:?}", Large::P1010), "P1010");
+            let name = match Large::new(1001).unwrap() {
+                Large::P1000 => "first",
+                Large::P1001 => "second",
+                _ => "other",
+            };
+            assert_eq!(name, "second");
+        }
+    }
+
+    mod aligned {
+        use super::bounded_integer;
+        use core::mem::align_of;
+
+        bounded_integer! {
+            #[repr(u8, align(4))]
+            struct AlignedStruct { 0..10 }
+        }
+        bounded_integer! {
+            #[repr(align(4), u8)]
+            enum AlignedEnum { 0..10 }
+        }
+
+        #[test]
+        fn align() {
+            assert_eq!(align_of::<AlignedStruct>(), 4);
+            assert_eq!(align_of::<AlignedEnum>(), 4);
+            assert_eq!(AlignedStruct::new(5).unwrap().get(), 5);
+            assert_eq!(AlignedEnum::new(5).unwrap().get(), 5);
+            assert_eq!(*AlignedStruct::MAX.get_ref(), 9);
+            assert_eq!(*AlignedEnum::MAX.get_ref(), 9);
+        }
+    }
+
+    #[cfg(feature = "niche")]
+    mod niche {
+        use super::bounded_integer;
+        use core::mem::size_of;
+
+        bounded_integer! {
+            #[niche]
+            struct Percent { 0..=100 }
+        }
+        bounded_integer! {
+            #[niche]
+            struct Signed { -5..=5 }
+        }
+        bounded_integer! {
+            #[niche]
+            #[repr(offset)]
+            struct Offset { 1000..=1100 }
+        }
+        bounded_integer! {
+            #[niche]
+            #[repr(u32)]
+            struct Wide { 1..=4_294_967_295 }
+        }
+
+        #[test]
+        fn option_size() {
+            assert_eq!(size_of::<Option<Percent>>(), size_of::<Percent>());
+            assert_eq!(size_of::<Option<Signed>>(), size_of::<Signed>());
+            assert_eq!(size_of::<Option<Offset>>(), size_of::<Offset>());
+            assert_eq!(size_of::<Option<Wide>>(), size_of::<Wide>());
+        }
+
+        #[test]
+        fn values() {
+            assert_eq!(Percent::new(100).map(Percent::get), Some(100));
+            assert_eq!(Percent::new(101), None);
+            assert_eq!(Percent::MAX.get(), 100);
+            for n in -5..=5 {
+                assert_eq!(Signed::new(n).map(Signed::get), Some(n));
+            }
+            assert_eq!(Signed::new(6), None);
+            assert_eq!(Signed::MIN + 10, Signed::MAX);
+            assert_eq!(Offset::new(1000).map(Offset::get), Some(1000));
+            assert_eq!(Offset::new(999), None);
+            assert_eq!(Offset::new_saturating(2000).get(), 1100);
+            assert_eq!(Wide::new(0), None);
+            assert_eq!(Wide::new(u32::MAX).map(Wide::get), Some(u32::MAX));
+        }
+    }
+
+    mod repr_c {
+        use super::bounded_integer;
+        use core::mem::{align_of, size_of};
+
+        bounded_integer! {
+            #[repr(C, u8)]
+            enum CEnum { 0..10 }
+        }
+        bounded_integer! {
+            #[repr(C)]
+            enum InferredCEnum { -1..300 }
+        }
+        bounded_integer! {
+            #[repr(C)]
+            struct CStruct { 0..10 }
+        }
+
+        #[test]
+        fn layout() {
+            assert_eq!(size_of::<CEnum>(), 1);
+            assert_eq!(align_of::<CEnum>(), 1);
+            assert_eq!(size_of::<InferredCEnum>(), size_of::<core::ffi::c_int>());
+            assert_eq!(align_of::<InferredCEnum>(), align_of::<core::ffi::c_int>());
+            assert_eq!(size_of::<CStruct>(), 1);
+            assert_eq!(CEnum::new(5).unwrap().get(), 5);
+            assert_eq!(InferredCEnum::MIN.get(), -1);
+            assert_eq!(InferredCEnum::MAX.get(), 299_i32);
+            assert_eq!(CStruct::MAX.get(), 9);
+        }
+    }
+
+    mod start_len {
+        use super::bounded_integer;
+        bounded_integer! {
+            struct Struct { start: -2, len: 5 }
+        }
+        bounded_integer! {
+            enum Enum { start: 10, len: 1 + 1 }
+        }
+
+        #[test]
+        fn range() {
+            assert_eq!(Struct::MIN_VALUE, -2);
+            assert_eq!(Struct::MAX_VALUE, 2);
+            assert_eq!(Enum::MIN_VALUE, 10);
+            assert_eq!(Enum::MAX_VALUE, 11);
+        }
+    }
+
+    mod single_value {
+        use super::bounded_integer;
+        use core::mem::size_of;
+        bounded_integer! {
+            struct Struct { 5..=5 }
+        }
+        bounded_integer! {
+            enum Enum { start: -1_000_000_000_000, len: 1 }
+        }
+        bounded_integer! {
+            #[repr(u8)]
+            struct Sized { 5..=5 }
+        }
+
+        #[test]
+        fn zero_sized() {
+            assert_eq!(size_of::<Struct>(), 0);
+            assert_eq!(size_of::<Enum>(), 0);
+            assert_eq!(size_of::<Sized>(), 1);
+        }
+
+        #[test]
+        fn value() {
+            extern crate std;
+            use std::format;
+
+            assert_eq!(Struct::MIN.get(), 5);
+            assert_eq!(*Struct::MAX.get_ref(), 5);
+            assert_eq!(Struct::new(5), Some(Struct::MIN));
+            assert_eq!(Struct::new(6), None);
+            assert_eq!(Struct::new_saturating(0).get(), 5);
+            assert_eq!(format!("{:?}", Struct::MIN), "Struct(5)");
+
+            assert_eq!(Enum::MIN.get(), -1_000_000_000_000);
+            assert_eq!(Enum::new(-1_000_000_000_000), Some(Enum::MAX));
+            assert_eq!(Enum::new(0), None);
+
+            assert_eq!(Sized::MIN.get(), 5);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    mod serde_offset {
+        use super::bounded_integer;
+        bounded_integer! {
+            struct Value { 1000..=1010 }
+        }
+        bounded_integer! {
+            #[serde(offset)]
+            struct Offset { 1000..=1010 }
+        }
+        bounded_integer! {
+            #[serde(offset)]
+            enum Enum { -5..5 }
+        }
+
+        #[test]
+        fn round_trip() {
+            let value = Value::new(1005).unwrap();
+            let json = serde_json::to_string(&value).unwrap();
+            assert_eq!(json, "1005");
+            assert_eq!(serde_json::from_str::<Value>(&json).unwrap(), value);
+
+            let offset = Offset::new(1005).unwrap();
+            let json = serde_json::to_string(&offset).unwrap();
+            assert_eq!(json, "5");
+            assert_eq!(serde_json::from_str::<Offset>(&json).unwrap(), offset);
+
+            for n in -5..5 {
+                let value = Enum::new(n).unwrap();
+                let json = serde_json::to_string(&value).unwrap();
+                assert_eq!(serde_json::from_str::<Enum>(&json).unwrap(), value);
+            }
+            assert_eq!(serde_json::to_string(&Enum::MIN).unwrap(), "0");
+        }
+
+        #[test]
+        fn smaller() {
+            let value = serde_json::to_string(&Value::MAX).unwrap();
+            let offset = serde_json::to_string(&Offset::MAX).unwrap();
+            assert!(offset.len() < value.len());
+        }
+
+        #[test]
+        fn out_of_range() {
+            assert!(serde_json::from_str::<Offset>("11").is_err());
+            assert!(serde_json::from_str::<Offset>("1000").is_err());
+            assert!(serde_json::from_str::<Enum>("10").is_err());
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    mod serde_direction {
+        use super::bounded_integer;
+        bounded_integer! {
+            #[serde(serialize_only)]
+            struct Output { 0..10 }
+        }
+        bounded_integer! {
+            #[serde(deserialize_only)]
+            enum Input { 0..10 }
+        }
+        bounded_integer! {
+            #[serde(offset, serialize_only)]
+            struct OffsetOutput { 100..110 }
+        }
+
+        #[test]
+        fn one_direction() {
+            assert_eq!(serde_json::to_string(&Output::MAX).unwrap(), "9");
+            assert_eq!(serde_json::from_str::<Input>("9").unwrap(), Input::MAX);
+            assert!(serde_json::from_str::<Input>("10").is_err());
+            assert_eq!(serde_json::to_string(&OffsetOutput::MAX).unwrap(), "9");
+        }
+    }
+
+    #[cfg(feature = "zerocopy")]
+    mod zerocopy_bytes {
+        use super::bounded_integer;
+        use zerocopy::{IntoBytes, TryFromBytes};
+
+        bounded_integer! {
+            #[repr(i16)]
+            struct Level { -100..100 }
+        }
+        bounded_integer! {
+            #[repr(i16)]
+            enum Reading { -50..50 }
+        }
+
+        #[test]
+        fn into_bytes() {
+            let level = Level::new(-42).unwrap();
+            assert_eq!(level.as_bytes(), (-42_i16).to_ne_bytes());
+            let reading = Reading::new(49).unwrap();
+            assert_eq!(reading.as_bytes(), 49_i16.to_ne_bytes());
+        }
+
+        #[test]
+        fn try_from_valid_bytes() {
+            for n in -50..50_i16 {
+                let reading = Reading::try_read_from_bytes(&n.to_ne_bytes()).unwrap();
+                assert_eq!(reading.get(), n);
+            }
+        }
+
+        #[test]
+        fn try_from_invalid_bytes() {
+            for n in [-32768, -51, 50, 12345, 32767_i16] {
+                assert!(Reading::try_read_from_bytes(&n.to_ne_bytes()).is_err());
+            }
+            assert!(Reading::try_read_from_bytes(&[0]).is_err());
+            assert!(Reading::try_read_from_bytes(&[0, 0, 0]).is_err());
+        }
+
+        #[test]
+        fn struct_from_valid_bytes() {
+            for n in -100..100_i16 {
+                let level = Level::try_read_from_bytes(&n.to_ne_bytes()).unwrap();
+                assert_eq!(level.get(), n);
+
+                let mut primitive = n;
+                let level = Level::try_ref_from_bytes(primitive.as_bytes()).unwrap();
+                assert_eq!(level.get(), n);
+                let level = Level::try_mut_from_bytes(primitive.as_mut_bytes()).unwrap();
+                *level = Level::MIN;
+                assert_eq!(primitive, -100);
+            }
+        }
+
+        #[test]
+        fn struct_from_invalid_bytes() {
+            for n in [-32768, -101, 100, 12345, 32767_i16] {
+                assert_eq!(Level::try_read_from_bytes(&n.to_ne_bytes()), None);
+                let mut primitive = n;
+                assert_eq!(Level::try_ref_from_bytes(primitive.as_bytes()), None);
+                assert_eq!(Level::try_mut_from_bytes(primitive.as_mut_bytes()), None);
+            }
+            assert_eq!(Level::try_read_from_bytes(&[0]), None);
+            assert_eq!(Level::try_read_from_bytes(&[0, 0, 0]), None);
+            let primitive = [0_i16; 2];
+            // Misaligned by one byte.
+            assert_eq!(Level::try_ref_from_bytes(&primitive.as_bytes()[1..3]), None);
+        }
+    }
+
+    mod cfg {
+        use super::bounded_integer;
+
+        bounded_integer! {
+            #[cfg(feature = "alloc")]
+            pub struct Gated { 0..10 }
+        }
+        // This would conflict with the bounded integer if it weren't configured out.
+        #[cfg(not(feature = "alloc"))]
+        #[allow(dead_code)]
+        pub struct Gated;
+
+        bounded_integer! {
+            #[cfg(any())]
+            enum Never { 0..10 }
+        }
+        struct Never;
+
+        bounded_integer! {
+            #[cfg(any())]
+            #[in_module(hidden)]
+            pub struct Hidden { 0..10 }
+        }
+        mod hidden {}
+
+        #[cfg(feature = "alloc")]
+        bounded_integer! {
+            struct Outer { 0..10 }
+        }
+
+        #[test]
+        #[cfg(feature = "alloc")]
+        fn enabled() {
+            assert_eq!(Gated::MAX.get(), 9);
+            assert_eq!(Outer::MAX.get(), 9);
+        }
+
+        #[test]
+        fn disabled() {
+            let _ = Never;
+        }
+    }
+
+    mod in_module {
+        use super::bounded_integer;
+        bounded_integer! {
+            #[in_module(units)]
+            pub struct Meters { 0..=1000 }
+        }
+        bounded_integer! {
+            #[in_module(cards)]
+            enum Rank { 1..=13 }
+        }
+
+        #[test]
+        fn path() {
+            assert_eq!(units::Meters::MAX_VALUE, 1000);
+            assert_eq!(cards::Rank::MIN.get(), 1);
+            assert_eq!(self::cards::Rank::new(14), None);
+        }
+    }
+
+    mod namespace {
+        use super::bounded_integer;
+        bounded_integer! {
+            #[namespace(notes)]
+            pub enum Note { 0..128 }
+        }
+        bounded_integer! {
+            #[namespace(levels)]
+            struct Level { -3..=3 }
+        }
+
+        // Names that the module and the type's variants would clash with if they leaked out.
+        const P60: u8 = 60;
+        #[allow(non_snake_case)]
+        mod __bounded_integer_private_Note {}
+
+        #[test]
+        fn reexported() {
+            let note: Note = notes::Note::P60;
+            assert_eq!(note.get(), P60);
+            assert_eq!(note, Note::P60);
+            assert_eq!(notes::Note::MAX.get(), 127);
+            assert_eq!(Level::MIN, levels::Level::new(-3).unwrap());
+        }
+    }
+
+    mod clock {
+        use super::bounded_integer;
+        bounded_integer! {
+            struct Hour { 0..=11 }
+        }
+        bounded_integer! {
+            struct Modulus { 1..=12 }
+        }
+
+        #[test]
+        fn reduce() {
+            for m in 1..=12 {
+                let modulus = Modulus::new(m).unwrap();
+                for value in [0, 5, 11, 12, 13, 100, 255] {
+                    assert!(Modulus::reduce(value, modulus) < m);
+                }
+            }
+            assert_eq!(Modulus::reduce(14, Modulus::MAX), 2);
+        }
+
+        #[test]
+        fn wrapping_distance() {
+            let h = |n| Hour::new(n).unwrap();
+            assert_eq!(h(10).wrapping_distance(h(2)), 4);
+            assert_eq!(h(2).wrapping_distance(h(10)), 8);
+        }
+    }
+
+    mod buffer_size {
+        use super::bounded_integer;
+        bounded_integer! {
+            struct BufferSize { 1..=100 }
+        }
+        bounded_integer! {
+            enum SmallSize { 1..=8 }
+        }
+        bounded_integer! {
+            struct FullByte { 0..=255 }
+        }
+
+        #[test]
+        fn checked_next_power_of_two() {
+            let size = |n| BufferSize::new(n).unwrap();
+            assert_eq!(size(5).checked_next_power_of_two(), Some(size(8)));
+            assert_eq!(size(64).checked_next_power_of_two(), Some(size(64)));
+            assert_eq!(size(65).checked_next_power_of_two(), None);
+            assert_eq!(
+                SmallSize::P3.checked_next_power_of_two(),
+                Some(SmallSize::P4)
+            );
+            assert_eq!(
+                SmallSize::MAX.checked_next_power_of_two(),
+                Some(SmallSize::P8)
+            );
+            assert_eq!(FullByte::MAX.checked_next_power_of_two(), None);
+        }
+
+        #[test]
+        fn isqrt() {
+            bounded_integer! {
+                struct Area { 50..=10_000 }
+            }
+
+            for n in 0..=255_u8 {
+                let value = FullByte::new(n).unwrap();
+                assert_eq!(value.isqrt().get(), n.isqrt());
+                assert_eq!(value.checked_isqrt(), Some(value.isqrt()));
+            }
+            const ROOT: BufferSize = BufferSize::MAX.isqrt();
+            assert_eq!(ROOT.get(), 10);
+            assert_eq!(SmallSize::P8.isqrt(), SmallSize::P2);
+
+            // Roots below 50 are out of range, which they are for every area under 2500.
+            assert_eq!(Area::MIN.checked_isqrt(), None);
+            assert_eq!(Area::new(2499).unwrap().checked_isqrt(), None);
+            assert_eq!(Area::new(2500).unwrap().checked_isqrt(), Area::new(50));
+            assert_eq!(Area::MAX.checked_isqrt(), Area::new(100));
+        }
+    }
+
+    mod from_str {
+        use super::bounded_integer;
+        use crate::ParseErrorKind;
+
+        bounded_integer! {
+            struct Count { 0..=100 }
+        }
+        bounded_integer! {
+            enum Level { 1..=9 }
+        }
+
+        #[test]
+        fn signs() {
+            assert_eq!("+5".parse(), Ok(Count::new(5).unwrap()));
+            assert_eq!("5".parse(), Ok(Count::new(5).unwrap()));
+            assert_eq!("+5".parse(), Ok(Level::P5));
+            let kind = |s: &str| s.parse::<Count>().unwrap_err().kind();
+            assert_eq!(kind("-5"), ParseErrorKind::BelowMin);
+        }
+
+        #[test]
+        fn bounds() {
+            assert_eq!("-0".parse(), Ok(Count::MIN));
+            assert_eq!("100".parse(), Ok(Count::MAX));
+            let kind = |s: &str| s.parse::<Level>().unwrap_err().kind();
+            assert_eq!(kind("0"), ParseErrorKind::BelowMin);
+            assert_eq!(kind("10"), ParseErrorKind::AboveMax);
+            assert_eq!(kind("5 "), ParseErrorKind::InvalidDigit);
+        }
+    }
+
+    mod ascii_digit {
+        use super::bounded_integer;
+        use core::convert::TryFrom;
+
+        bounded_integer! {
+            struct AsciiDigit { 48..=57 }
+        }
+        bounded_integer! {
+            enum Scalar { 0xD7FE..=0xD7FF }
+        }
+
+        #[test]
+        fn char_conversions() {
+            let digit = AsciiDigit::try_from('5').unwrap();
+            assert_eq!(digit.get(), 53);
+            assert_eq!(char::from(digit), '5');
+            assert_eq!(AsciiDigit::try_from('0'), Ok(AsciiDigit::MIN));
+            assert_eq!(AsciiDigit::try_from('9'), Ok(AsciiDigit::MAX));
+            assert!(AsciiDigit::try_from('a').is_err());
+            assert!(AsciiDigit::try_from('/').is_err());
+            assert!(AsciiDigit::try_from('\u{10035}').is_err());
+            assert_eq!(char::from(Scalar::MAX), '\u{D7FF}');
+            assert_eq!(Scalar::try_from('\u{D7FE}'), Ok(Scalar::MIN));
+        }
+    }
+
+    mod const_range {
+        use super::bounded_integer;
+        use core::ops::RangeInclusive;
+
+        const LEVELS: RangeInclusive<i32> = -10..=10;
+        mod config {
+            pub(super) const PORTS: core::ops::RangeInclusive<u16> = 1024..=49151;
+        }
+
+        bounded_integer! {
+            #[repr(i32)]
+            struct Level { LEVELS }
+        }
+        bounded_integer! {
+            #[repr(u16)]
+            #[in_module(ports)]
+            struct Port { config::PORTS }
+        }
+
+        #[test]
+        fn bounds() {
+            assert_eq!(Level::MIN_VALUE, -10);
+            assert_eq!(Level::MAX.get(), 10);
+            assert_eq!(Level::RANGE, LEVELS);
+            assert_eq!(Level::SIZE_BITS, 5);
+            assert_eq!(ports::Port::MIN.get(), 1024);
+            assert_eq!(ports::Port::MAX_VALUE, 49151);
+            assert_eq!(ports::Port::SIZE_BITS, 16);
+        }
+
+        #[test]
+        fn membership() {
+            assert_eq!(Level::new(-11), None);
+            assert_eq!(Level::new(3).map(Level::get), Some(3));
+            assert_eq!(Level::new_saturating(100), Level::MAX);
+            assert_eq!(Level::MAX.checked_add(1), None);
+            assert_eq!(ports::Port::new(80), None);
+            assert_eq!("8080".parse::<ports::Port>().unwrap().get(), 8080);
+        }
+
+        #[test]
+        fn in_function() {
+            const DICE: RangeInclusive<u8> = 1..=6;
+            bounded_integer! {
+                #[repr(u8)]
+                struct Die { DICE }
+            }
+            assert_eq!(Die::new(0), None);
+            assert_eq!(Die::MAX.get(), 6);
+        }
+
+        #[cfg(feature = "types")]
+        #[test]
+        fn types_eq() {
+            assert_eq!(Level::MIN, crate::BoundedI32::<-10, 10>::MIN);
+        }
+    }
+
+    #[cfg(feature = "types")]
+    mod types_eq {
+        use super::bounded_integer;
+        use crate::{BoundedI16, BoundedU8};
+        bounded_integer! {
+            struct Small { 0..=7 }
+        }
+        bounded_integer! {
+            #[repr(i16)]
+            enum Offset { -3..3 }
+        }
+
+        #[test]
+        fn eq() {
+            let small = Small::new(5).unwrap();
+            assert_eq!(small, BoundedU8::<0, 7>::new(5).unwrap());
+            assert_eq!(BoundedU8::<0, 7>::new(5).unwrap(), small);
+            assert_ne!(small, BoundedU8::<0, 7>::MAX);
+
+            assert_eq!(Offset::MIN, BoundedI16::<-3, 2>::MIN);
+            assert_ne!(BoundedI16::<-3, 2>::MAX, Offset::N1);
+        }
+    }
+
+    mod repr_offset {
+        use super::bounded_integer;
+        bounded_integer! {
+            #[repr(offset)]
+            struct Low { -200..=-100 }
+        }
+        bounded_integer! {
+            #[repr(offset)]
+            struct Negative { -100..=-50 }
+        }
+        bounded_integer! {
+            #[repr(u64, offset)]
+            struct Far { 1_000_000..1_000_010 }
+        }
+
+        #[test]
+        fn get() {
+            assert_eq!(Low::MIN.get(), -200_i16);
+            assert_eq!(Low::MAX.get(), -100_i16);
+            assert_eq!(Low::new(-150).unwrap().get(), -150);
+            assert_eq!(Low::new(-99), None);
+            assert_eq!(Low::new_saturating(-1000), Low::MIN);
+            assert_eq!(Low::MIN.checked_add(70).map(Low::get), Some(-130));
+            assert_eq!(Negative::new(-75).unwrap().get(), -75_i8);
+            assert_eq!(Far::MAX.get(), 1_000_009_u64);
+            assert!(Low::MIN < Low::MAX);
+        }
+
+        #[test]
+        fn stored_unsigned() {
+            // The offsets are stored, so the types are as small as `u8`.
+            assert_eq!(core::mem::size_of::<Low>(), 1);
+            assert_eq!(core::mem::size_of::<Far>(), 1);
+            let stored = |n| unsafe { core::mem::transmute::<Low, u8>(n) };
+            assert_eq!(stored(Low::MIN), 0);
+            assert_eq!(stored(Low::MAX), 100);
+            let stored =
+                unsafe { core::mem::transmute::<Negative, u8>(Negative::new(-75).unwrap()) };
+            assert_eq!(stored, 25);
+            let stored = unsafe { core::mem::transmute::<Far, u8>(Far::new(1_000_003).unwrap()) };
+            assert_eq!(stored, 3);
+        }
+
+        #[test]
+        fn debug() {
+            extern crate std;
+            use std::format;
+
+            assert_eq!(format!("{:?}", Low::MIN), "Low(-200)");
+        }
+    }
+
+    mod saturating_step {
+        use super::bounded_integer;
+        bounded_integer! {
+            struct Volume { 0..=100 }
+        }
+        bounded_integer! {
+            enum Gear { -1..=5 }
+        }
+
+        #[test]
+        fn step() {
+            let v = Volume::new(95).unwrap();
+            let louder: Volume = v.saturating_step(10);
+            assert_eq!(louder, Volume::MAX);
+            assert_eq!(v.saturating_step(-20), 75);
+            assert_eq!(v.saturating_step(i8::MIN), Volume::MIN);
+            assert_eq!(Volume::MIN.saturating_step(i8::MAX), Volume::MAX);
+
+            assert_eq!(Gear::Z.saturating_step(3), Gear::P3);
+            assert_eq!(Gear::Z.saturating_step(-3), Gear::MIN);
+            assert_eq!(Gear::P4.saturating_step(2), Gear::MAX);
+        }
+    }
+
+    mod packed {
+        use super::bounded_integer;
+        bounded_integer! {
+            #[repr(u16)]
+            struct Len { 0..=1500 }
+        }
+        bounded_integer! {
+            #[repr(u32)]
+            enum Kind { 1..=3 }
+        }
+
+        #[repr(C, packed)]
+        struct Header {
+            tag: u8,
+            len: Len,
+            kind: Kind,
+        }
+
+        #[test]
+        fn packed_field() {
+            use core::mem::{align_of, size_of};
+
+            assert_eq!(size_of::<Len>(), size_of::<u16>());
+            assert_eq!(align_of::<Len>(), align_of::<u16>());
+            assert_eq!(size_of::<Header>(), 7);
+
+            let mut header = Header {
+                tag: 1,
+                len: Len::new(1200).unwrap(),
+                kind: Kind::P2,
+            };
+            // Methods taking `self` by value copy the unaligned field out.
+            assert_eq!(header.len.get(), 1200);
+            assert_eq!({ header.kind }, Kind::P2);
+            header.len = Len::MAX;
+            let len = header.len;
+            assert_eq!(len, 1500);
+            assert_eq!(header.tag, 1);
+        }
+    }
+
+    mod nonzero {
+        use super::bounded_integer;
+        use core::convert::TryFrom;
+        use core::num::{NonZeroI16, NonZeroU8};
+        bounded_integer! {
+            struct Positive { 1..=10 }
+        }
+        bounded_integer! {
+            #[repr(i16)]
+            enum Negative { -5..=-1 }
+        }
+
+        #[test]
+        fn conversions() {
+            let n = Positive::new(7).unwrap();
+            assert_eq!(NonZeroU8::from(n).get(), 7);
+            assert_eq!(
+                Positive::try_from(NonZeroU8::new(10).unwrap()),
+                Ok(Positive::MAX)
+            );
+            assert!(Positive::try_from(NonZeroU8::new(11).unwrap()).is_err());
+
+            assert_eq!(NonZeroI16::from(Negative::MIN).get(), -5);
+            assert_eq!(
+                Negative::try_from(NonZeroI16::new(-1).unwrap()),
+                Ok(Negative::MAX)
+            );
+            assert!(Negative::try_from(NonZeroI16::new(1).unwrap()).is_err());
+        }
+    }
+
+    mod bitfield {
+        use super::bounded_integer;
+        bounded_integer! {
+            struct Nibble { 0..=0xF }
+        }
+        bounded_integer! {
+            enum Flags { 0..=0b111 }
+        }
+
+        #[test]
+        fn rotate() {
+            let n = Nibble::new(0b0110).unwrap();
+            assert_eq!(n.rotate_left(1), Nibble::new(0b1100));
+            assert_eq!(n.rotate_right(1), Nibble::new(0b0011));
+            assert_eq!(n.rotate_left(8), Some(n));
+            assert_eq!(n.rotate_left(3), None);
+            assert_eq!(n.rotate_right(2), None);
+            assert_eq!(Flags::P1.rotate_left(2), Some(Flags::P4));
+            assert_eq!(Flags::P4.rotate_left(1), None);
+        }
+
+        #[test]
+        fn pack() {
+            let nibble = Nibble::new(0xA).unwrap();
+            let word = Flags::P5.pack_into(nibble.pack_into(0, 0), Nibble::SIZE_BITS);
+            assert_eq!(word, 0b101_1010);
+            assert_eq!(Nibble::unpack_from(word, 0), Some(nibble));
+            assert_eq!(Flags::unpack_from(word, Nibble::SIZE_BITS), Some(Flags::P5));
+
+            let word = super::BoundedStruct::MIN.pack_into(u64::MAX, 60);
+            assert_eq!(word, !(0xF << 60));
+            assert_eq!(
+                super::BoundedEnum::unpack_from(word, 60),
+                Some(super::BoundedEnum::MIN)
+            );
+        }
+
+        #[test]
+        #[should_panic = "bit field does not fit in the word"]
+        fn pack_overflow() {
+            let _ = Nibble::MAX.pack_into(0, 61);
+        }
+    }
+
+    mod float {
+        use super::bounded_integer;
+        bounded_integer! {
+            struct Percent { 0..=100 }
+        }
+        bounded_integer! {
+            #[repr(i32)]
+            enum Small { -5..5 }
+        }
+        bounded_integer! {
+            #[repr(u32)]
+            struct Wide { 0..=4_294_967_295 }
+        }
+
+        #[test]
+        fn normal() {
+            assert_eq!(Percent::checked_from_f32(42.0).unwrap().get(), 42);
+            assert_eq!(Percent::checked_from_f32(-0.0), Some(Percent::MIN));
+            assert_eq!(Percent::checked_from_f32(42.5), None);
+            assert_eq!(Percent::checked_from_f32(101.0), None);
+            assert_eq!(Percent::checked_from_f32(f32::NAN), None);
+            assert_eq!(Percent::from_f32_saturating(99.9).get(), 99);
+            assert_eq!(Percent::from_f32_saturating(1000.0), Percent::MAX);
+            assert_eq!(
+                Percent::from_f32_saturating(f32::NEG_INFINITY),
+                Percent::MIN
+            );
+
+            assert_eq!(Small::checked_from_f32(-5.0), Some(Small::MIN));
+            assert_eq!(Small::checked_from_f32(-5.5), None);
+            assert_eq!(Small::from_f32_saturating(-4.5).get(), -4);
+        }
+
+        #[test]
+        fn precision_loss() {
+            // 2^24 is the last integer before `f32` starts skipping them.
+            let exact = Wide::checked_from_f32(16_777_216.0).unwrap();
+            assert_eq!(exact.get(), 16_777_216);
+            #[allow(clippy::excessive_precision)]
+            let rounded = Wide::checked_from_f32(16_777_217.0).unwrap();
+            assert_eq!(rounded, exact);
+
+            // The maximum rounds up past the end of `u32`.
+            #[allow(clippy::excessive_precision)]
+            let max = 4_294_967_295.0;
+            assert_eq!(Wide::checked_from_f32(max), None);
+            assert_eq!(Wide::from_f32_saturating(max), Wide::MAX);
+        }
+
+        #[test]
+        fn normalized() {
+            bounded_integer! {
+                struct Fixed { 3..=3 }
+            }
+
+            const HALF: f64 = Percent::new_const::<50>().normalized();
+            assert_eq!(HALF, 0.5);
+            assert_eq!(Percent::MIN.normalized(), 0.0);
+            assert_eq!(Percent::MAX.normalized(), 1.0);
+            assert_eq!(Small::MIN.normalized(), 0.0);
+            assert_eq!(Small::MAX.normalized(), 1.0);
+            assert!((Wide::new(u32::MAX / 2).unwrap().normalized() - 0.5).abs() < 1e-9);
+            assert_eq!(Fixed::MIN.normalized(), 0.0);
+        }
+
+        #[test]
+        fn from_normalized() {
+            const HALF: Option<Percent> = Percent::from_normalized(0.5);
+            assert_eq!(HALF.map(Percent::get), Some(50));
+            assert_eq!(Percent::from_normalized(0.0), Some(Percent::MIN));
+            assert_eq!(Percent::from_normalized(1.0), Some(Percent::MAX));
+            assert_eq!(Percent::from_normalized(f64::NAN), None);
+            assert_eq!(Percent::from_normalized(1.5), None);
+
+            for value in Small::variants() {
+                assert_eq!(Small::from_normalized(value.normalized()), Some(value));
+            }
+            assert_eq!(Wide::from_normalized(1.0), Some(Wide::MAX));
+        }
+
+        #[test]
+        fn to_float() {
+            for value in [Percent::MIN, Percent::new(42).unwrap(), Percent::MAX] {
+                assert_eq!(value.to_f64(), f64::from(value.get()));
+                assert_eq!(value.to_f32(), f32::from(value.get()));
+            }
+            for value in Small::variants() {
+                assert_eq!(value.to_f64(), value.get() as f64);
+            }
+
+            // `f64` holds every `u32` exactly, but `f32` rounds the maximum up to 2^32.
+            assert_eq!(Wide::MAX.to_f64(), 4_294_967_295.0);
+            assert_eq!(Wide::MAX.to_f32(), 4_294_967_296.0);
+        }
+    }
+
+    mod pagination {
+        use super::bounded_integer;
+        bounded_integer! {
+            struct Pages { 0..=10 }
+        }
+        bounded_integer! {
+            enum Offset { -10..=10 }
+        }
+
+        #[test]
+        fn div_ceil() {
+            let items = Pages::new(7).unwrap();
+            assert_eq!(items.checked_div_ceil(3).unwrap().get(), 3);
+            assert_eq!(items.checked_div_floor(3).unwrap().get(), 2);
+            assert_eq!(items.checked_div_ceil(0), None);
+            assert_eq!(items.checked_div_floor(0), None);
+        }
+
+        #[test]
+        fn signed() {
+            for n in -10..=10_i8 {
+                for d in [-4, -3, -1, 1, 2, 5] {
+                    let exact = f64::from(n) / f64::from(d);
+                    let floor = Offset::new(n).unwrap().checked_div_floor(d).unwrap();
+                    let ceil = Offset::new(n).unwrap().checked_div_ceil(d).unwrap();
+                    assert!(
+                        f64::from(floor.get()) <= exact && exact < f64::from(floor.get()) + 1.0
+                    );
+                    assert!(f64::from(ceil.get()) - 1.0 < exact && exact <= f64::from(ceil.get()));
+                }
+            }
+            let n = Offset::new(-7).unwrap();
+            assert_eq!(n.checked_div_floor(2).unwrap().get(), -4);
+            assert_eq!(n.checked_div_ceil(2).unwrap().get(), -3);
+            assert_eq!(n.checked_div_floor(0), None);
+        }
+    }
+
+    mod layout_bounds {
+        use super::bounded_integer;
+        use core::mem;
+        bounded_integer! {
+            struct BitIndex { 0..size_of::<u32>() * 8 }
+        }
+        bounded_integer! {
+            struct Shift { -(core::mem::size_of::<u16>())..=mem::align_of::<u8>() }
+        }
+
+        #[test]
+        fn bounds() {
+            assert_eq!(BitIndex::MIN_VALUE, 0);
+            assert_eq!(BitIndex::MAX_VALUE, 31);
+            assert_eq!(
+                usize::from(BitIndex::MAX_VALUE) + 1,
+                mem::size_of::<u32>() * 8
+            );
+            assert_eq!(Shift::MIN_VALUE, -2);
+            assert_eq!(Shift::MAX_VALUE, 1);
+        }
+    }
+
+    mod negative_enum {
+        use super::bounded_integer;
+        bounded_integer! {
+            enum Temp { -2..=1 }
+        }
+        bounded_integer! {
+            #[repr(i16)]
+            enum Wide { -300..-298 }
+        }
+
+        #[test]
+        fn discriminants() {
+            let variants = [(Temp::N2, -2), (Temp::N1, -1), (Temp::Z, 0), (Temp::P1, 1)];
+            for (variant, value) in variants {
+                assert_eq!(variant.get(), value);
+                assert_eq!(variant as i8, value);
+                assert_eq!(Temp::new(value), Some(variant));
+            }
+            assert_eq!(Temp::new(-3), None);
+            assert_eq!(Temp::new(2), None);
+
+            assert_eq!(Wide::N300.get(), -300);
+            assert_eq!(Wide::N299 as i16, -299);
+            assert_eq!(Wide::new(-300), Some(Wide::N300));
+            assert_eq!(Wide::new(-298), None);
+        }
+    }
+
+    mod value_consts {
+        use super::bounded_integer;
+        bounded_integer! {
+            #[value_consts]
+            enum Digit { 0..10 }
+        }
+        bounded_integer! {
+            #[value_consts]
+            struct Offset { -3..=3 }
+        }
+        bounded_integer! {
+            #[value_consts]
+            struct Only { 7..=7 }
+        }
+
+        #[test]
+        fn values() {
+            const FIVE: Digit = Digit::V5;
+            assert_eq!(FIVE, Digit::P5);
+            assert_eq!(Digit::V0, Digit::Z);
+            assert_eq!(Digit::V9, Digit::MAX);
+            assert_eq!(Offset::VN3, Offset::MIN);
+            assert_eq!(Offset::VN1.get(), -1);
+            assert_eq!(Offset::V0.get(), 0);
+            assert_eq!(Offset::V3, Offset::MAX);
+            assert_eq!(Only::V7, Only::MIN);
+        }
+    }
+
+    mod display {
+        use super::bounded_integer;
+        bounded_integer! {
+            #[display(format = "{}°C")]
+            pub struct Celsius { -40..=50 }
+        }
+        bounded_integer! {
+            #[display(format = "{{{:03}}}")]
+            enum Code { 0..=10 }
+        }
+
+        #[test]
+        fn decorated() {
+            extern crate std;
+            use std::format;
+
+            let temp = Celsius::new(-5).unwrap();
+            assert_eq!(format!("{}", temp), "-5°C");
+            assert_eq!(format!("{:?}", temp), "Celsius(-5)");
+            assert_eq!(format!("{:x}", Celsius::MAX), "32");
+            assert_eq!(format!("{}", Code::P7), "{007}");
+        }
+
+        #[test]
+        #[cfg(feature = "alloc")]
+        fn to_string() {
+            extern crate std;
+            use std::string::ToString;
+
+            assert_eq!(Celsius::MAX.to_string(), "50°C");
+        }
+    }
+
+    mod signed_diff {
+        use super::bounded_integer;
+        bounded_integer! {
+            struct Celsius { -40..=50 }
+        }
+        bounded_integer! {
+            struct Byte { 0..=255 }
+        }
+        bounded_integer! {
+            #[repr(u32)]
+            enum Year { 2000..2010 }
+        }
+        bounded_integer! {
+            struct Single { 7..=7 }
+        }
+
+        #[test]
+        fn difference() {
+            let c = |n| Celsius::new(n).unwrap();
+            let diff: i8 = c(20).signed_diff(c(-5));
+            assert_eq!(diff, 25);
+            assert_eq!(c(-5).signed_diff(c(20)), -25);
+            assert_eq!(Celsius::MAX.signed_diff(Celsius::MIN), 90);
+            assert_eq!(Celsius::MIN.signed_diff(Celsius::MAX), -90);
+
+            let diff: i16 = Byte::MIN.signed_diff(Byte::MAX);
+            assert_eq!(diff, -255);
+            assert_eq!(Byte::MAX.signed_diff(Byte::MIN), 255);
+
+            let y = |n| Year::new(n).unwrap();
+            let diff: i8 = y(2003).signed_diff(y(2009));
+            assert_eq!(diff, -6);
+            assert_eq!(y(2009).signed_diff(y(2000)), 9);
+
+            let diff: i8 = Single::MIN.signed_diff(Single::MAX);
+            assert_eq!(diff, 0);
+        }
+    }
+
+    mod two_values {
+        use super::bounded_integer;
+        bounded_integer! {
+            struct Bit { 0..=1 }
+        }
+        bounded_integer! {
+            enum Pair { 5..=6 }
+        }
+
+        #[test]
+        fn round_trip() {
+            const ON: Bit = Bit::from_bool(true);
+            assert_eq!(ON, Bit::MAX);
+            assert_eq!(Bit::from_bool(false), Bit::MIN);
+            assert_eq!(Pair::from_bool(false).get(), 5);
+            assert_eq!(Pair::from_bool(true).get(), 6);
+
+            for b in [false, true] {
+                assert_eq!(Bit::from_bool(b).to_bool(), b);
+                assert_eq!(Pair::from_bool(b).to_bool(), b);
+            }
+            for n in 5..=6 {
+                let pair = Pair::new(n).unwrap();
+                assert_eq!(Pair::from_bool(pair.to_bool()), pair);
+            }
+        }
+    }
+
+    mod no_cmp {
+        use super::bounded_integer;
+        bounded_integer! {
+            #[no_eq]
+            struct Token { 0..100 }
+        }
+        bounded_integer! {
+            #[no_ord]
+            enum Suit { 0..4 }
+        }
+
+        #[test]
+        fn without_cmp() {
+            assert_eq!(Token::new(5).unwrap().get(), 5);
+            assert!(Token::new(100).is_none());
+            assert_eq!(Suit::new(3), Some(Suit::P3));
+            assert_eq!(Suit::P2, 2);
+        }
+    }
+
+    mod size_bits {
+        use super::bounded_integer;
+        bounded_integer! {
+            struct Octal { 0..=7 }
+        }
+        bounded_integer! {
+            enum Nonal { 0..=8 }
+        }
+        bounded_integer! {
+            struct Wide { -1000..=1000 }
+        }
+        bounded_integer! {
+            struct Single { 5..=5 }
+        }
+
+        #[test]
+        fn size_bits() {
+            assert_eq!(Octal::SIZE_BITS, 3);
+            assert_eq!(Nonal::SIZE_BITS, 4);
+            assert_eq!(Wide::SIZE_BITS, 11);
+            assert_eq!(Single::SIZE_BITS, 0);
+            assert_eq!(super::BoundedStruct::SIZE_BITS, 4);
+        }
+
+        #[test]
+        fn repr_bits() {
+            assert_eq!(Octal::REPR_BITS, u8::BITS);
+            assert_eq!(Nonal::REPR_BITS, u8::BITS);
+            assert_eq!(Wide::REPR_BITS, i16::BITS);
+            // A zero-sized type still has a repr, the smallest one that fits its value.
+            assert_eq!(Single::REPR_BITS, u8::BITS);
+            assert_eq!(super::BoundedStruct::REPR_BITS, 8);
+        }
+    }
+
+    mod extreme_128 {
+        use super::bounded_integer;
+        bounded_integer! {
+            struct Top {
+                340_282_366_920_938_463_463_374_607_431_768_211_000
+                    ..=340_282_366_920_938_463_463_374_607_431_768_211_455
+            }
+        }
+        bounded_integer! {
+            struct Bottom {
+                -170_141_183_460_469_231_731_687_303_715_884_105_728
+                    ..-170_141_183_460_469_231_731_687_303_715_884_105_000
+            }
+        }
+        bounded_integer! {
+            struct FullU128 { 0..=340_282_366_920_938_463_463_374_607_431_768_211_455 }
+        }
+        bounded_integer! {
+            struct FullI128 {
+                -170_141_183_460_469_231_731_687_303_715_884_105_728
+                    ..=170_141_183_460_469_231_731_687_303_715_884_105_727
+            }
+        }
+
+        #[test]
+        fn bounds() {
+            assert_eq!(Top::MAX_VALUE, u128::MAX);
+            assert_eq!(Top::MIN_VALUE, u128::MAX - 455);
+            assert_eq!(Top::new(u128::MAX).unwrap(), Top::MAX);
+            assert_eq!(Top::new(0), None);
+
+            assert_eq!(Bottom::MIN_VALUE, i128::MIN);
+            assert_eq!(Bottom::MAX.get(), i128::MIN + 727);
+            assert_eq!(Bottom::new_saturating(0), Bottom::MAX);
+
+            assert_eq!(FullU128::MAX.get(), u128::MAX);
+            assert_eq!(FullI128::MIN.get(), i128::MIN);
+            assert_eq!(FullI128::MAX.get(), i128::MAX);
+        }
+    }
+
+    mod no_copy {
+        use super::bounded_integer;
+        bounded_integer! {
+            #[no_copy]
+            #[repr(u128)]
+            struct Large { 0..=1_000_000 }
+        }
+        bounded_integer! {
+            #[no_copy]
+            enum Small { -5..5 }
+        }
+
+        #[test]
+        fn clone() {
+            let large = Large::new(1000).unwrap();
+            assert_eq!(large.clone(), large);
+            assert_eq!(large.get(), 1000);
+            assert_eq!(&large + 5, 1005);
+            assert_eq!(large.checked_add(1_000_000), None);
+            assert_eq!([Large::MIN, Large::MAX].iter().sum::<u128>(), 1_000_000);
+
+            let mut small = Small::P1;
+            small += Small::P2;
+            assert_eq!(small.get(), 3);
+            assert_eq!(small.wrapping_distance(Small::N4), 3);
+        }
+    }
+
+    mod literals {
+        use super::bounded_integer;
+        bounded_integer! {
+            struct Underscores { 1_000..=1_000_000 }
+        }
+        bounded_integer! {
+            struct Radixes { 0x10..0b1_0000_0000 }
+        }
+        bounded_integer! {
+            struct Suffixed { 0_u8..=100i32 }
+        }
+
+        #[test]
+        fn range() {
+            assert_eq!(Underscores::MIN_VALUE, 1000);
+            assert_eq!(Underscores::MAX_VALUE, 1_000_000);
+            assert_eq!(Radixes::MIN_VALUE, 16);
+            assert_eq!(Radixes::MAX_VALUE, 255);
+            assert_eq!(Suffixed::MIN_VALUE, 0);
+            assert_eq!(Suffixed::MAX_VALUE, 100);
+        }
+    }
+
+    mod correct_reprs {
+        use super::bounded_integer;
+        bounded_integer! {
+            struct ByteStruct { 0..256 }
+        }
+        const _: u8 = ByteStruct::MIN_VALUE;
+        bounded_integer! {
+            enum ByteEnum { 0..256 }
+        }
+        const _: u8 = ByteEnum::MIN_VALUE;
+
+        bounded_integer! {
+            struct SignedByteStruct { -128..128 }
+        }
+        const _: i8 = SignedByteStruct::MIN_VALUE;
+        bounded_integer! {
             struct SignedByteEnum { -128..128 }
         }
         const _: i8 = SignedByteEnum::MIN_VALUE;
+
+        #[test]
+        fn full_range_not() {
+            assert_eq!((!ByteStruct::new(5).unwrap()).get(), !5);
+            assert_eq!((!ByteEnum::new(5).unwrap()).get(), !5);
+            assert_eq!(!&SignedByteStruct::MIN, SignedByteStruct::MAX);
+        }
+
+        #[test]
+        fn full_range_new() {
+            for n in 0..=255 {
+                assert_eq!(ByteStruct::new(n).unwrap().get(), n);
+                assert_eq!(ByteEnum::new_saturating(n).get(), n);
+            }
+            for n in -128..=127 {
+                assert_eq!(SignedByteEnum::new(n).unwrap().get(), n);
+            }
+        }
+
+        #[test]
+        fn full_range_saturating_abs() {
+            // The absolute value of the primitive's minimum saturates instead of overflowing.
+            assert_eq!(SignedByteStruct::MIN.saturating_abs().get(), 127);
+            assert_eq!(SignedByteEnum::MIN.saturating_abs(), SignedByteEnum::MAX);
+            assert_eq!(SignedByteStruct::new(-5).unwrap().saturating_abs().get(), 5);
+            assert_eq!(SignedByteStruct::new(5).unwrap().saturating_abs().get(), 5);
+        }
     }
 }
@@ -0,0 +1,15 @@
+/// Mixes a value into a 64-bit seed using the SplitMix64 finalizer on both of its halves. Signed
+/// values are sign-extended first, so the seed depends only on the value and not the primitive or
+/// platform.
+#[must_use]
+pub const fn hash_seed(value: u128) -> u64 {
+    let (high, low) = ((value >> 64) as u64, value as u64);
+    mix(low ^ mix(high))
+}
+
+const fn mix(mut z: u64) -> u64 {
+    z = z.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
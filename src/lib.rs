@@ -34,32 +34,128 @@
 #![cfg_attr(not(feature = "types"), doc = "# }")]
 //! ```
 //!
+//! Their bounds can be any constant expression, so they can also be taken from a named range:
+//!
+//! ```rust
+#![cfg_attr(feature = "step_trait", doc = "# #![feature(step_trait)]")]
+#![cfg_attr(not(feature = "types"), doc = "# #[cfg(any())] {")]
+//! # use bounded_integer::BoundedI32;
+//! # use core::ops::RangeInclusive;
+//! const RANGE: RangeInclusive<i32> = -10..=10;
+//! type Level = BoundedI32<{ *RANGE.start() }, { *RANGE.end() }>;
+//! assert_eq!(Level::MAX, 10);
+#![cfg_attr(not(feature = "types"), doc = "# }")]
+//! ```
+//!
 //! These integers are shorter to use as they don't require a type declaration or explicit name,
 //! and they interoperate better with other integers that have different ranges. However due to the
-//! limits of const generics, they do not implement some traits like `Default`.
+//! limits of const generics, some of their trait implementations differ. For example, they always
+//! implement `Default`: it gives zero like the macro-generated types, or the value nearest to zero
+//! when zero is out of range, whereas the macro only implements it when zero is in range.
+//!
+//! There is a separate type for each primitive, such as `BoundedU8` and `BoundedI16`. There is
+//! also `Bounded<T, MIN, MAX>`, which is generic over its primitive `T`. As the type of a const
+//! generic parameter cannot depend on another generic parameter, its bounds are given as `i128`,
+//! and it has fewer methods than the types with one primitive each:
+//!
+//! ```rust
+#![cfg_attr(feature = "step_trait", doc = "# #![feature(step_trait)]")]
+#![cfg_attr(not(feature = "types"), doc = "# #[cfg(any())] {")]
+//! # use bounded_integer::Bounded;
+//! let num = <Bounded<u8, 0, 7>>::new(5).unwrap();
+//! assert_eq!(num, 5);
+#![cfg_attr(not(feature = "types"), doc = "# }")]
+//! ```
+//!
+//! # Generic code
+//!
+//! Both kinds of bounded integer implement the [`BoundedInteger`] trait, which allows writing code
+//! that is generic over them and converting between them with
+//! [`saturating_cast_to`](BoundedInteger::saturating_cast_to). The [`Checked`] wrapper builds on
+//! it to run a chain of arithmetic that is only checked for going out of range at the end.
+//!
+//! # Arithmetic
+//!
+//! The bounded integers implement the arithmetic operators with themselves and with their
+//! primitive, as in `n + 1`, and return the bounded integer. A result that is out of range panics
+//! in both debug and release builds, because a bounded integer can never hold it. Overflowing the
+//! primitive itself follows the primitive's rules: it panics in debug builds and wraps in release
+//! builds, and the wrapped value is then checked against the range. To handle out of range
+//! results without panicking, use the `checked_*` and `saturating_*` methods or [`Checked`].
 //!
 //! # `no_std`
 //!
 //! All the integers in this crate depend only on libcore and so work in `#![no_std]` environments.
+//! The `alloc` feature additionally requires liballoc.
 //!
 //! # Crate Features
 //!
 //! By default, no crate features are enabled.
 //! - `macro`: Enable the [`bounded_integer!`] macro.
 //! - `types`: Enable the bounded integer types that use const generics.
+//! - `alloc`: Add a `to_string` method to the bounded integers that formats them without the
+//!   overhead of the [`Display`](core::fmt::Display) machinery. This depends on liballoc.
 //! - `serde`: Implement `Serialize` and `Deserialize` for the bounded integers, making sure all
-//! values will never be out of bounds.
+//!   values will never be out of bounds.
+//! - `zerocopy`: Implement [`IntoBytes`], [`Immutable`] and [`KnownLayout`] for the bounded
+//!   integers, and [`TryFromBytes`] for the enum-form ones generated by the macro. `FromBytes` is
+//!   never implemented, since arbitrary bytes can be out of range. zerocopy only allows
+//!   `TryFromBytes` to be derived, and the derive can't check the range of a struct, so the
+//!   struct-form types instead get `try_read_from_bytes`, `try_ref_from_bytes` and
+//!   `try_mut_from_bytes` methods that return `None` for bytes that are out of range.
 //! - `step_trait`: Implement the [`Step`] trait which allows the bounded integers to be easily used
-//! in ranges. This will require you to use nightly and place `#![feature(step_trait)]` in your
-//! crate root if you use the macro.
+//!   in ranges. This will require you to use nightly and place `#![feature(step_trait)]` in your
+//!   crate root if you use the macro.
+//! - `niche`: Allow the `#[niche]` attribute of the macro, which gives structs a niche so that
+//!   `Option` of them is no larger than they are. This will require you to use nightly and place
+//!   `#![feature(pattern_types, pattern_type_macro)]` in your crate root.
 //!
 //! [`bounded_integer!`]: https://docs.rs/bounded-integer/*/bounded_integer/macro.bounded_integer.html
 //! [`examples`]: https://docs.rs/bounded-integer/*/bounded_integer/examples/
 //! [`Step`]: https://doc.rust-lang.org/nightly/core/iter/trait.Step.html
+//! [`IntoBytes`]: https://docs.rs/zerocopy/0.8/zerocopy/trait.IntoBytes.html
+//! [`Immutable`]: https://docs.rs/zerocopy/0.8/zerocopy/trait.Immutable.html
+//! [`KnownLayout`]: https://docs.rs/zerocopy/0.8/zerocopy/trait.KnownLayout.html
+//! [`TryFromBytes`]: https://docs.rs/zerocopy/0.8/zerocopy/trait.TryFromBytes.html
 #![cfg_attr(feature = "step_trait", feature(step_trait))]
+#![cfg_attr(
+    all(test, feature = "niche"),
+    feature(pattern_types, pattern_type_macro)
+)]
+#![cfg_attr(all(test, feature = "niche"), allow(internal_features))]
 #![cfg_attr(doc_cfg, feature(doc_cfg))]
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+mod traits;
+pub use traits::{BoundedInteger, Primitive};
+
+mod checked;
+pub use checked::Checked;
+
+#[cfg(any(feature = "types", feature = "macro"))]
+mod parse;
+#[cfg(any(feature = "types", feature = "macro"))]
+pub use parse::{ParseError, ParseErrorKind};
+
+#[cfg(any(feature = "types", feature = "macro"))]
+mod try_from;
+#[cfg(any(feature = "types", feature = "macro"))]
+pub use try_from::TryFromError;
+
+#[cfg(all(feature = "alloc", any(feature = "types", feature = "macro")))]
+mod to_string;
+
+#[cfg(any(feature = "types", feature = "macro"))]
+mod hash_seed;
+
+#[cfg(feature = "types")]
+mod bounded;
+#[cfg(feature = "types")]
+pub use bounded::Bounded;
+
 #[cfg(feature = "types")]
 mod types;
 #[cfg(feature = "types")]
@@ -70,6 +166,20 @@ pub use types::*;
 pub mod __private {
     #[cfg(feature = "serde")]
     pub use ::serde;
+    #[cfg(feature = "zerocopy")]
+    pub use ::zerocopy;
+
+    #[cfg(feature = "alloc")]
+    pub use {crate::to_string::to_string, alloc::string::String};
+
+    pub use crate::__bounded_integer_cfg_alloc as cfg_alloc;
+    pub use crate::__bounded_integer_cfg_types as cfg_types;
+    pub use crate::__bounded_integer_cfg_zerocopy as cfg_zerocopy;
+    pub use crate::__bounded_integer_derive_zerocopy as derive_zerocopy;
+    pub use crate::__bounded_integer_require_niche as require_niche;
+    pub use crate::hash_seed::hash_seed;
+    pub use crate::parse::from_ascii;
+    pub use crate::try_from::try_from_error;
 
     #[cfg(all(not(feature = "serde"), not(feature = "step_trait")))]
     pub use bounded_integer_macro::not_serde_not_step_trait as proc_macro;
@@ -81,14 +191,103 @@ pub mod __private {
     pub use bounded_integer_macro::serde_step_trait as proc_macro;
 }
 
+/// Expands to its input only if the `alloc` feature is enabled. This allows the proc macro to
+/// generate code depending on the feature without needing an entry point for each combination.
+#[doc(hidden)]
+#[cfg(all(feature = "macro", feature = "alloc"))]
+#[macro_export]
+macro_rules! __bounded_integer_cfg_alloc {
+    ($($tt:tt)*) => { $($tt)* };
+}
+#[doc(hidden)]
+#[cfg(all(feature = "macro", not(feature = "alloc")))]
+#[macro_export]
+macro_rules! __bounded_integer_cfg_alloc {
+    ($($tt:tt)*) => {};
+}
+
+/// Expands to its input only if the `types` feature is enabled, like `__bounded_integer_cfg_alloc`.
+#[doc(hidden)]
+#[cfg(all(feature = "macro", feature = "types"))]
+#[macro_export]
+macro_rules! __bounded_integer_cfg_types {
+    ($($tt:tt)*) => { $($tt)* };
+}
+#[doc(hidden)]
+#[cfg(all(feature = "macro", not(feature = "types")))]
+#[macro_export]
+macro_rules! __bounded_integer_cfg_types {
+    ($($tt:tt)*) => {};
+}
+
+/// Expands to its input only if the `zerocopy` feature is enabled, like
+/// `__bounded_integer_cfg_alloc`.
+#[doc(hidden)]
+#[cfg(all(feature = "macro", feature = "zerocopy"))]
+#[macro_export]
+macro_rules! __bounded_integer_cfg_zerocopy {
+    ($($tt:tt)*) => { $($tt)* };
+}
+#[doc(hidden)]
+#[cfg(all(feature = "macro", not(feature = "zerocopy")))]
+#[macro_export]
+macro_rules! __bounded_integer_cfg_zerocopy {
+    ($($tt:tt)*) => {};
+}
+
+/// Fails to compile unless the `niche` feature is enabled, which the `#[niche]` attribute requires
+/// as it only works on nightly.
+#[doc(hidden)]
+#[cfg(all(feature = "macro", feature = "niche"))]
+#[macro_export]
+macro_rules! __bounded_integer_require_niche {
+    () => {};
+}
+#[doc(hidden)]
+#[cfg(all(feature = "macro", not(feature = "niche")))]
+#[macro_export]
+macro_rules! __bounded_integer_require_niche {
+    () => {
+        ::core::compile_error!("`#[niche]` requires the `niche` feature of bounded-integer");
+    };
+}
+
+/// Adds the given zerocopy derives to an item if the `zerocopy` feature is enabled.
+#[doc(hidden)]
+#[cfg(all(feature = "macro", feature = "zerocopy"))]
+#[macro_export]
+macro_rules! __bounded_integer_derive_zerocopy {
+    ([$($trait:ident),*] $($item:tt)*) => {
+        use $crate::__private::zerocopy as __zerocopy;
+        #[derive($(__zerocopy::$trait),*)]
+        #[zerocopy(crate = "self::__zerocopy")]
+        $($item)*
+    };
+}
+#[doc(hidden)]
+#[cfg(all(feature = "macro", not(feature = "zerocopy")))]
+#[macro_export]
+macro_rules! __bounded_integer_derive_zerocopy {
+    ([$($trait:ident),*] $($item:tt)*) => { $($item)* };
+}
+
 #[cfg(feature = "__examples")]
 pub mod examples;
 
+#[cfg(doctest)]
+mod compile_fail;
+
 /// Generate a bounded integer type.
 ///
 /// It takes in single struct or enum, with the content being a bounded range expression, whose
-/// upper bound can be inclusive (`x..=y`) or exclusive (`x..y`). The attributes and visibility
-/// (e.g. `pub`) of the type are forwarded directly to the output type.
+/// upper bound can be inclusive (`x..=y`) or exclusive (`x..y`). The range can alternatively be
+/// given as a start and a length, as in `start: x, len: n`, which is equivalent to `x..x + n`. The
+/// attributes and visibility (e.g. `pub`) of the type are forwarded directly to the output type.
+/// `#[cfg(...)]` attributes also apply to everything else the macro generates, so the whole
+/// definition can be configured out.
+///
+/// The macro expands to items, so it can be used wherever items can, including inside a function
+/// body to define a type local to that function. It cannot be used in expression position.
 ///
 /// See the [`examples`] module for examples of what this macro generates.
 ///
@@ -106,7 +305,7 @@ pub mod examples;
 /// ```
 /// The generated item should look like this (i8 is chosen as it is the smallest repr):
 /// ```
-/// #[derive(Debug, Hash, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 /// #[repr(transparent)]
 /// pub struct S(i8);
 /// ```
@@ -124,12 +323,21 @@ pub mod examples;
 /// ```
 /// The generated item should look like this (u8 is chosen as it is the smallest repr):
 /// ```
-/// #[derive(Debug, Hash, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 /// #[repr(u8)]
 /// pub enum S {
 ///     P5 = 5, P6, P7
 /// }
 /// ```
+/// Because the values outside the range are not valid variants, the compiler can use them to store
+/// the discriminant of an enclosing enum, so `Option<S>` is the same size as `S`. Structs only
+/// have this niche when given the `#[niche]` attribute on nightly, as described below.
+///
+/// An enum with more than 128 values would take long to compile, so it is generated as a
+/// `#[repr(transparent)]` struct instead. It keeps the associated items of an enum: an associated
+/// constant stands in for each variant, so `S::P5` can still be used in expressions and patterns,
+/// and `VARIANTS`, `variant_name` and the `Debug` output are the same. It can't be cast with `as`,
+/// matching on it needs a wildcard arm, and it has no niche.
 ///
 /// # Custom repr
 ///
@@ -149,19 +357,242 @@ pub mod examples;
 /// ```
 /// The generated item should look like this:
 /// ```
-/// #[derive(Debug, Hash, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 /// #[repr(transparent)]
 /// pub struct S(u16);
 /// ```
 ///
+/// The `repr` attribute can also contain `C` for types used in FFI. Structs are given
+/// `#[repr(C)]`. An enum given only `#[repr(C)]` keeps it, so it has the size of a C `int`; it uses
+/// `i32` as its primitive, so its range must fit in an `i32`. An enum given a primitive as well is
+/// generated with just the primitive (`#[repr(C, u8)]` becomes `#[repr(u8)]`), since Rust rejects
+/// the combination and a fieldless enum with a primitive `repr` already has that layout in C.
+///
+/// The `repr` attribute can also contain an alignment, which is forwarded to the generated type:
+///
+/// ```
+#[cfg_attr(feature = "step_trait", doc = "# #![feature(step_trait)]")]
+/// # mod force_item_scope {
+/// # use bounded_integer::bounded_integer;
+/// bounded_integer! {
+///     #[repr(u8, align(4))]
+///     pub struct S { 2..5 }
+/// }
+/// # }
+/// ```
+/// The generated item should look like this:
+/// ```
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+/// #[repr(C, align(4))]
+/// pub struct S(u8);
+/// ```
+/// Because an aligned type no longer has the same layout as its primitive, it does not have the
+/// methods that create references to it from references to primitives (`new_ref`, `new_mut` and
+/// their unchecked variants), nor `from_repr_unchecked`.
+///
+/// Without an alignment, the type has the same size and alignment as its primitive. It can be a
+/// field of a `#[repr(packed)]` struct, where it may be unaligned, so it must be read by copying
+/// it out, as in `{ header.len }`, or through a method that takes `self` by value such as `get`,
+/// and never through a reference. `packed` cannot be given in the `repr` of the bounded integer
+/// itself, because methods like `get_ref` return references to its value.
+///
+/// A struct can instead store its offset from the minimum, by adding `offset` to the `repr`. The
+/// offset is kept in the smallest unsigned primitive that can hold it, so a range far from zero
+/// takes less space; `get` adds the minimum back. The value is not stored anywhere, so such a type
+/// has none of the methods or traits that give references to it (`get_ref`, `get_mut`, `AsRef`,
+/// `Borrow`, `new_ref` and the like), and it doesn't implement the zerocopy traits.
+///
+/// ```
+#[cfg_attr(feature = "step_trait", doc = "# #![feature(step_trait)]")]
+/// # use bounded_integer::bounded_integer;
+/// bounded_integer! {
+///     #[repr(offset)]
+///     pub struct Altitude { -1200..=-1000 }
+/// }
+/// assert_eq!(Altitude::MIN.get(), -1200_i16);
+/// assert_eq!(core::mem::size_of::<Altitude>(), 1);
+/// ```
+///
+/// # Niche
+///
+/// With the `niche` feature enabled on nightly, the `#[niche]` attribute makes a struct's field a
+/// pattern type restricted to the range, so the compiler can use the values outside the range to
+/// store the discriminant of an enclosing enum, as it does for enums. This needs
+/// `#![feature(pattern_types, pattern_type_macro)]` in your crate root. The range must leave at
+/// least one value of the primitive unused. As pattern types don't implement the zerocopy traits,
+/// such a type doesn't either.
+///
+#[cfg_attr(feature = "niche", doc = "```")]
+#[cfg_attr(not(feature = "niche"), doc = "```ignore")]
+#[cfg_attr(feature = "step_trait", doc = "# #![feature(step_trait)]")]
+/// #![feature(pattern_types, pattern_type_macro)]
+/// # use bounded_integer::bounded_integer;
+/// bounded_integer! {
+///     #[niche]
+///     pub struct Percent { 0..=100 }
+/// }
+/// assert_eq!(core::mem::size_of::<Option<Percent>>(), 1);
+/// ```
+///
+/// # Single-value ranges
+///
+/// A range containing only one value, such as `5..=5`, generates a zero-sized type if no `repr`
+/// attribute is given. Its value is known statically, so `get` returns it as a constant:
+///
+/// ```
+#[cfg_attr(feature = "step_trait", doc = "# #![feature(step_trait)]")]
+/// # use bounded_integer::bounded_integer;
+/// bounded_integer! {
+///     struct Five { 5..=5 }
+/// }
+/// assert_eq!(core::mem::size_of::<Five>(), 0);
+/// assert_eq!(Five::MIN.get(), 5);
+/// ```
+/// Like aligned types, zero-sized types lack the methods that convert to and from references to
+/// primitives, and additionally lack `get_mut`.
+///
+/// # Modules
+///
+/// The `#[in_module(name)]` attribute places the type inside a new module with the given name,
+/// which has the same visibility as the type:
+///
+/// ```
+#[cfg_attr(feature = "step_trait", doc = "# #![feature(step_trait)]")]
+/// # mod force_item_scope {
+/// # use bounded_integer::bounded_integer;
+/// bounded_integer! {
+///     #[in_module(units)]
+///     pub struct Meters { 0..=1000 }
+/// }
+/// # fn f() -> units::Meters { units::Meters::MIN }
+/// # }
+/// ```
+///
+/// `#[namespace(name)]` does the same but also re-exports the type from the enclosing scope, so it
+/// can be named either way while everything else the macro generates stays inside the module.
+///
+/// ```
+#[cfg_attr(feature = "step_trait", doc = "# #![feature(step_trait)]")]
+/// # use bounded_integer::bounded_integer;
+/// bounded_integer! {
+///     #[namespace(notes)]
+///     pub enum Note { 0..128 }
+/// }
+/// let middle_c: notes::Note = Note::P60;
+/// assert_eq!(middle_c.get(), 60);
+/// ```
+///
+/// # Comparisons
+///
+/// The type normally implements `PartialEq`, `Eq`, `PartialOrd` and `Ord`, both with itself and
+/// with its primitive. For types where comparing values is meaningless, such as opaque tokens, the
+/// `#[no_eq]` attribute removes all of these and `#[no_ord]` removes only the ordering traits.
+/// Without `Ord`, the type does not implement [`BoundedInteger`].
+///
+/// With the `types` feature enabled, the type can also be compared for equality with the const
+/// generic type of the same repr and range, such as `BoundedU8<0, 7>` for `struct S { 0..=7 }`.
+///
+/// # Copying
+///
+/// The type normally implements `Copy`. For large types such as those with a `u128` `repr`, the
+/// `#[no_copy]` attribute makes it only implement `Clone`, to avoid copying it by accident. Its
+/// `get` method then takes `&self`, and it does not implement [`BoundedInteger`].
+///
+/// # Display
+///
+/// The type normally implements `Display` by printing its value like its primitive. The
+/// `#[display(format = "...")]` attribute instead formats the value with the given format string,
+/// which must contain exactly one `{}` placeholder, optionally with a format spec like `{:02}`.
+/// This only affects `Display` and so `to_string`; parsing and serialization still use the bare
+/// number.
+///
+/// ```
+#[cfg_attr(feature = "step_trait", doc = "# #![feature(step_trait)]")]
+/// # use bounded_integer::bounded_integer;
+/// bounded_integer! {
+///     #[display(format = "{}°C")]
+///     pub struct Celsius { -40..=50 }
+/// }
+/// assert_eq!(format!("{}", Celsius::new(21).unwrap()), "21°C");
+/// ```
+///
+/// # Value constants
+///
+/// The `#[value_consts]` attribute adds an associated constant for every value in the range, named
+/// `V` followed by the value, or `VN` followed by its magnitude for negative values. This lets you
+/// write `Digit::V5` instead of `Digit::new(5).unwrap()`. It is limited to ranges of at most 256
+/// values.
+///
+/// ```
+#[cfg_attr(feature = "step_trait", doc = "# #![feature(step_trait)]")]
+/// # use bounded_integer::bounded_integer;
+/// bounded_integer! {
+///     #[value_consts]
+///     pub struct Offset { -2..=2 }
+/// }
+/// assert_eq!(Offset::VN2, Offset::MIN);
+/// assert_eq!(Offset::V1.get(), 1);
+/// ```
+///
+/// # Constant ranges
+///
+/// A struct with a primitive in its `#[repr]` can take its range from a `RangeInclusive` constant,
+/// given by its path. The macro runs before constants are evaluated, so `MIN_VALUE` and
+/// `MAX_VALUE` are read from the constant, and compilation fails if its start is after its end.
+///
+/// ```
+#[cfg_attr(feature = "step_trait", doc = "# #![feature(step_trait)]")]
+/// # use bounded_integer::bounded_integer;
+/// # use core::ops::RangeInclusive;
+/// const LEVELS: RangeInclusive<i32> = -10..=10;
+/// bounded_integer! {
+///     #[repr(i32)]
+///     pub struct Level { LEVELS }
+/// }
+/// assert_eq!(Level::MAX_VALUE, 10);
+/// ```
+///
+/// Without the bounds, the macro can't generate enums, `#[value_consts]`, `#[niche]` or
+/// `#[repr(offset)]`, which reject such a range. It also leaves out the items that only exist for
+/// some ranges: `Default`, `Sum`, `Product`, `isqrt`, `reduce`, the conversions to and from
+/// `char`, `bool` and non-zero integers, and the `as_*` methods for primitives that can't hold
+/// every value of the `repr`.
+///
+/// # Serde
+///
+/// With the `serde` feature enabled, the type is serialized as its underlying primitive. Adding a
+/// `#[serde(offset)]` attribute to the item makes it serialize the offset of the value from the
+/// minimum instead, as an unsigned integer. This takes fewer bytes in variable-length encodings
+/// when the minimum is large.
+///
+/// To implement only one direction, add `#[serde(serialize_only)]` or
+/// `#[serde(deserialize_only)]`. These can be combined with `offset`, as in
+/// `#[serde(offset, serialize_only)]`.
+///
+/// # Zerocopy
+///
+/// With the `zerocopy` feature enabled, the type implements `IntoBytes`, `Immutable` and
+/// `KnownLayout`. Enums also implement `TryFromBytes`, which rejects bytes outside the range. Types
+/// with an `align` in their `repr` have padding, and single-value enums have no `repr`, so neither
+/// of them implement the traits.
+///
+/// Structs can't implement `TryFromBytes`, so those that implement the other traits have
+/// `try_read_from_bytes`, `try_ref_from_bytes` and `try_mut_from_bytes` methods instead. They work
+/// like the methods of the trait, but return `None` if the bytes have the wrong length or
+/// alignment or hold a value outside the range.
+///
 /// # Limitations
 ///
 /// - Both bounds of ranges must be closed and a simple const expression involving only literals and
-/// the following operators:
+///   the following operators:
 ///     - Negation (`-x`)
 ///     - Addition (`x+y`), subtraction (`x-y`), multiplication (`x*y`), division (`x/y`) and
-///     remainder (`x%y`).
+///       remainder (`x%y`).
 ///     - Bitwise not (`!x`), XOR (`x^y`), AND (`x&y`) and OR (`x|y`).
+///     - Calls to `size_of::<T>()` and `align_of::<T>()`, optionally prefixed with `mem::`,
+///       `core::mem::` or `std::mem::`, where `T` is a primitive whose layout is the same on every
+///       target. The macro cannot run const fns, so it evaluates these itself; this excludes
+///       `usize`, `isize`, and the alignment of anything wider than a byte.
 #[cfg(feature = "macro")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "macro")))]
 #[macro_export]
@@ -170,3 +601,36 @@ macro_rules! bounded_integer {
         $crate::__private::proc_macro!([$crate] $($tt)*);
     };
 }
+
+/// Create a bounded integer from a constant, failing to compile if it is out of range.
+///
+/// This takes the bounded integer type and the value, and can be used in `const` contexts. It
+/// works with both macro-generated and const generics-based bounded integers.
+///
+/// # Examples
+///
+/// ```
+#[cfg_attr(feature = "step_trait", doc = "# #![feature(step_trait)]")]
+#[cfg_attr(not(feature = "types"), doc = "# #[cfg(any())] {")]
+/// use bounded_integer::{assert_in_range, BoundedU8};
+///
+/// const PERCENT: BoundedU8<0, 100> = assert_in_range!(BoundedU8<0, 100>, 42);
+/// assert_eq!(PERCENT, 42);
+#[cfg_attr(not(feature = "types"), doc = "# }")]
+/// ```
+///
+/// A value outside the range is a compile error:
+///
+/// ```compile_fail
+#[cfg_attr(feature = "step_trait", doc = "# #![feature(step_trait)]")]
+#[cfg_attr(not(feature = "types"), doc = "# compile_error!(\"\");")]
+/// use bounded_integer::{assert_in_range, BoundedU8};
+///
+/// const PERCENT: BoundedU8<0, 100> = assert_in_range!(BoundedU8<0, 100>, 142);
+/// ```
+#[macro_export]
+macro_rules! assert_in_range {
+    ($ty:ty, $value:expr $(,)?) => {
+        <$ty>::new_const::<{ $value }>()
+    };
+}
@@ -0,0 +1,97 @@
+use core::fmt::{self, Display, Formatter};
+
+/// An error which can be returned when parsing a bounded integer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    kind: ParseErrorKind,
+}
+
+impl ParseError {
+    /// Gives the cause of the error.
+    #[must_use]
+    pub fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self.kind {
+            ParseErrorKind::Empty => "cannot parse integer from empty string",
+            ParseErrorKind::InvalidDigit => "invalid digit found in string",
+            ParseErrorKind::AboveMax => "number too large to fit in the bounded integer",
+            ParseErrorKind::BelowMin => "number too small to fit in the bounded integer",
+        })
+    }
+}
+
+/// The cause of a [`ParseError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseErrorKind {
+    /// The input was empty, or only contained a sign.
+    Empty,
+    /// The input contained a byte that is not an ASCII digit.
+    InvalidDigit,
+    /// The number was greater than the maximum of the bounded integer.
+    AboveMax,
+    /// The number was less than the minimum of the bounded integer.
+    BelowMin,
+}
+
+impl From<ParseErrorKind> for ParseError {
+    fn from(kind: ParseErrorKind) -> Self {
+        Self { kind }
+    }
+}
+
+/// Parses an optionally signed ASCII decimal integer, checking it against the bounds. All numbers
+/// are given as their sign and magnitude so that this works for every primitive.
+pub fn from_ascii(
+    bytes: &[u8],
+    min: (bool, u128),
+    max: (bool, u128),
+) -> Result<(bool, u128), ParseError> {
+    let (negative, digits) = match bytes {
+        [b'-', rest @ ..] => (true, rest),
+        [b'+', rest @ ..] => (false, rest),
+        _ => (false, bytes),
+    };
+    if digits.is_empty() {
+        return Err(ParseErrorKind::Empty.into());
+    }
+
+    let overflow = if negative {
+        ParseErrorKind::BelowMin
+    } else {
+        ParseErrorKind::AboveMax
+    };
+    let mut magnitude = 0_u128;
+    for &byte in digits {
+        if !byte.is_ascii_digit() {
+            return Err(ParseErrorKind::InvalidDigit.into());
+        }
+        magnitude = magnitude
+            .checked_mul(10)
+            .and_then(|n| n.checked_add(u128::from(byte - b'0')))
+            .ok_or(overflow)?;
+    }
+
+    // Normalize `-0` so that it compares equal to `0`.
+    let value = (negative && magnitude != 0, magnitude);
+    if less_than(value, min) {
+        Err(ParseErrorKind::BelowMin.into())
+    } else if less_than(max, value) {
+        Err(ParseErrorKind::AboveMax.into())
+    } else {
+        Ok(value)
+    }
+}
+
+fn less_than((a_negative, a): (bool, u128), (b_negative, b): (bool, u128)) -> bool {
+    match (a_negative, b_negative) {
+        (false, false) => a < b,
+        (true, true) => a > b,
+        (a_negative, _) => a_negative,
+    }
+}
@@ -0,0 +1,27 @@
+use alloc::string::String;
+
+/// Formats an integer in decimal given its sign and magnitude, writing the digits into a buffer
+/// on the stack so that the string is allocated exactly once with the right length.
+#[must_use]
+pub fn to_string(negative: bool, magnitude: u128) -> String {
+    // `u128::MAX` has 39 digits, plus one byte for the sign.
+    let mut buf = [0_u8; 40];
+    let mut start = buf.len();
+
+    let mut n = magnitude;
+    loop {
+        start -= 1;
+        buf[start] = b'0' + (n % 10) as u8;
+        n /= 10;
+        if n == 0 {
+            break;
+        }
+    }
+    if negative {
+        start -= 1;
+        buf[start] = b'-';
+    }
+
+    // SAFETY: The buffer only contains ASCII digits and the minus sign.
+    String::from(unsafe { core::str::from_utf8_unchecked(&buf[start..]) })
+}
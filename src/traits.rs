@@ -0,0 +1,213 @@
+use core::convert::TryFrom;
+use core::fmt::{Debug, Display};
+use core::hash::Hash;
+use sealed::Sealed;
+
+/// A bounded integer.
+///
+/// This is implemented by the types generated by the [`bounded_integer!`](crate::bounded_integer)
+/// macro and by the const generics-based bounded integers, allowing code to be generic over them.
+pub trait BoundedInteger: Copy + Ord {
+    /// The primitive integer type that this bounded integer is represented as.
+    type Repr: Primitive;
+
+    /// The smallest value that this bounded integer can contain.
+    const MIN_VALUE: Self::Repr;
+    /// The largest value that this bounded integer can contain.
+    const MAX_VALUE: Self::Repr;
+
+    /// Creates a bounded integer if the given value is within the range
+    /// [`MIN_VALUE`](Self::MIN_VALUE)..=[`MAX_VALUE`](Self::MAX_VALUE).
+    fn new(n: Self::Repr) -> Option<Self>;
+
+    /// Creates a bounded integer by setting the value to [`MIN_VALUE`](Self::MIN_VALUE) or
+    /// [`MAX_VALUE`](Self::MAX_VALUE) if it is too low or too high respectively.
+    fn new_saturating(n: Self::Repr) -> Self;
+
+    /// Returns the value of the bounded integer as a primitive type.
+    fn get(self) -> Self::Repr;
+
+    /// Converts this bounded integer to another bounded integer type, clamping the value to the
+    /// range of the target type.
+    #[must_use]
+    fn saturating_cast_to<B>(self) -> B
+    where
+        B: BoundedInteger,
+        B::Repr: TryFrom<Self::Repr>,
+    {
+        let value = self.get();
+        match B::Repr::try_from(value) {
+            Ok(value) => B::new_saturating(value),
+            // The value doesn't fit in the target's primitive, so it's beyond one of its ends.
+            Err(_) if value < Self::Repr::ZERO => B::new_saturating(B::MIN_VALUE),
+            Err(_) => B::new_saturating(B::MAX_VALUE),
+        }
+    }
+
+    /// Linearly maps this bounded integer from its range onto the range of another bounded
+    /// integer, so that the minimum maps to the minimum and the maximum to the maximum.
+    ///
+    /// The result is rounded to the nearest value, with halfway values rounded up. The
+    /// intermediate arithmetic is done in 256 bits, so it never overflows. If this type has only
+    /// one value, it maps to the target's minimum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    #[cfg_attr(feature = "step_trait", doc = "# #![feature(step_trait)]")]
+    #[cfg_attr(not(feature = "types"), doc = "# #[cfg(any())] {")]
+    /// use bounded_integer::{BoundedInteger, BoundedU16, BoundedU8};
+    ///
+    /// let reading = BoundedU16::<0, 1023>::new(767).unwrap();
+    /// assert_eq!(reading.remap_to::<BoundedU8<0, 100>>(), 75);
+    #[cfg_attr(not(feature = "types"), doc = "# }")]
+    /// ```
+    #[must_use]
+    fn remap_to<B: BoundedInteger>(self) -> B {
+        let source_width = Self::MAX_VALUE.offset_from(Self::MIN_VALUE);
+        let target_width = B::MAX_VALUE.offset_from(B::MIN_VALUE);
+        let offset = self.get().offset_from(Self::MIN_VALUE);
+        let target_offset = if source_width == 0 {
+            0
+        } else {
+            mul_div_round(offset, target_width, source_width)
+        };
+        // The offset is at most the target's width, so the value is always in range.
+        B::new_saturating(B::MIN_VALUE.add_offset(target_offset))
+    }
+}
+
+/// Computes `a * b / c` rounded to the nearest integer, where `a <= c` and `c != 0`.
+fn mul_div_round(a: u128, b: u128, c: u128) -> u128 {
+    let (hi, lo) = widening_mul(a, b);
+    let (lo, carry) = lo.overflowing_add(c / 2);
+    let hi = hi + u128::from(carry);
+
+    // Long division of the 256-bit numerator. As `a <= c` the quotient fits in 128 bits, and the
+    // remainder is kept below `c` except for the bit shifted out of it, which `overflow` tracks.
+    let mut remainder = hi;
+    let mut quotient = 0;
+    for i in (0..128).rev() {
+        let overflow = remainder >> 127 == 1;
+        remainder = (remainder << 1) | ((lo >> i) & 1);
+        quotient <<= 1;
+        if overflow || remainder >= c {
+            remainder = remainder.wrapping_sub(c);
+            quotient |= 1;
+        }
+    }
+    quotient
+}
+
+/// Computes the full 256-bit product of two integers as its high and low halves.
+fn widening_mul(a: u128, b: u128) -> (u128, u128) {
+    let mask = u128::from(u64::MAX);
+    let (a_lo, a_hi) = (a & mask, a >> 64);
+    let (b_lo, b_hi) = (b & mask, b >> 64);
+
+    let lo_lo = a_lo * b_lo;
+    let hi_lo = a_hi * b_lo;
+    let lo_hi = a_lo * b_hi;
+    let hi_hi = a_hi * b_hi;
+
+    let middle = (lo_lo >> 64) + (hi_lo & mask) + lo_hi;
+    let lo = (middle << 64) | (lo_lo & mask);
+    let hi = hi_hi + (hi_lo >> 64) + (middle >> 64);
+    (hi, lo)
+}
+
+/// A primitive integer type that a bounded integer can be represented as.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait Primitive: sealed::Sealed + Copy + Ord + Hash + Debug + Display {
+    /// The value zero.
+    const ZERO: Self;
+
+    /// Checked addition. Returns [`None`] if overflow occurred.
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    /// Checked subtraction. Returns [`None`] if overflow occurred.
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    /// Checked multiplication. Returns [`None`] if overflow occurred.
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    /// Checked division. Returns [`None`] if `rhs` is zero or overflow occurred.
+    fn checked_div(self, rhs: Self) -> Option<Self>;
+    /// Checked remainder. Returns [`None`] if `rhs` is zero or overflow occurred.
+    fn checked_rem(self, rhs: Self) -> Option<Self>;
+}
+
+pub(crate) mod sealed {
+    pub trait Sealed: Sized {
+        /// Returns how far `self` is above `base`, which must not be greater than it.
+        fn offset_from(self, base: Self) -> u128;
+        /// Returns the value `offset` above `self`, which must fit in the type.
+        fn add_offset(self, offset: u128) -> Self;
+    }
+
+    /// The bounds of a [`Bounded`](crate::Bounded) converted to the primitive. Using them fails to
+    /// compile if the bounds don't fit in the primitive or `MIN` is greater than `MAX`.
+    #[cfg(feature = "types")]
+    pub trait Bounds<const MIN: i128, const MAX: i128>: Sized {
+        const MIN: Self;
+        const MAX: Self;
+    }
+}
+
+/// Converts a bound to the primitive, failing to compile if it doesn't fit.
+#[cfg(feature = "types")]
+macro_rules! convert_bound {
+    ($ty:ident, $bound:expr) => {{
+        let converted = $bound as $ty;
+        // The conversion is lossless when it round-trips and keeps the sign.
+        #[allow(unused_comparisons)]
+        let fits = converted as i128 == $bound && (converted < 0) == ($bound < 0);
+        assert!(
+            fits,
+            concat!("bound does not fit in `", stringify!($ty), "`")
+        );
+        converted
+    }};
+}
+
+macro_rules! impl_primitive {
+    ($($ty:ident)*) => { $(
+        // Casting to `u128` sign-extends, which keeps the value the same modulo 2^128, so the
+        // wrapping arithmetic gives the exact offset.
+        impl sealed::Sealed for $ty {
+            fn offset_from(self, base: Self) -> u128 {
+                (self as u128).wrapping_sub(base as u128)
+            }
+            fn add_offset(self, offset: u128) -> Self {
+                (self as u128).wrapping_add(offset) as Self
+            }
+        }
+        #[cfg(feature = "types")]
+        impl<const MIN: i128, const MAX: i128> sealed::Bounds<MIN, MAX> for $ty {
+            const MIN: Self = {
+                assert!(MIN <= MAX, "MIN must not be greater than MAX");
+                convert_bound!($ty, MIN)
+            };
+            const MAX: Self = convert_bound!($ty, MAX);
+        }
+        impl Primitive for $ty {
+            const ZERO: Self = 0;
+
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                <$ty>::checked_add(self, rhs)
+            }
+            fn checked_sub(self, rhs: Self) -> Option<Self> {
+                <$ty>::checked_sub(self, rhs)
+            }
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                <$ty>::checked_mul(self, rhs)
+            }
+            fn checked_div(self, rhs: Self) -> Option<Self> {
+                <$ty>::checked_div(self, rhs)
+            }
+            fn checked_rem(self, rhs: Self) -> Option<Self> {
+                <$ty>::checked_rem(self, rhs)
+            }
+        }
+    )* };
+}
+
+impl_primitive!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
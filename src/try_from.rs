@@ -0,0 +1,19 @@
+use core::fmt::{self, Display, Formatter};
+
+/// The error type returned when a checked conversion into a bounded integer fails because the
+/// value is out of range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TryFromError(());
+
+impl Display for TryFromError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("out of range conversion to bounded integer attempted")
+    }
+}
+
+/// Creates a [`TryFromError`]. Its field is private so that it can be extended in the future.
+#[cfg(feature = "macro")]
+#[must_use]
+pub const fn try_from_error() -> TryFromError {
+    TryFromError(())
+}
@@ -116,6 +116,9 @@ macro_rules! test_arithmetic {
         $(let _: Bounded = Bounded::MIN.$infallible(0);)*
         $(let _: Option<Bounded> = Bounded::MIN.$fallible(0);)*
         let _: Option<Bounded> = Bounded::MIN.checked_neg();
+        let _: Option<Bounded> = Bounded::MIN.checked_not();
+        let _: Option<Bounded> = Bounded::MIN.checked_increment();
+        let _: Option<Bounded> = Bounded::MIN.checked_decrement();
     };
     (signed $($tt:tt)*) => {
         test_arithmetic!($($tt)*);
@@ -129,6 +132,164 @@ macro_rules! test_arithmetic {
     };
 }
 
+macro_rules! impl_as_primitive {
+    ($($into:ident)*) => { $(impl_as_primitive!(@as $into);)* };
+    (@as u8) => { impl_as_primitive!(@fn u8 as_u8); };
+    (@as u16) => { impl_as_primitive!(@fn u16 as_u16); };
+    (@as u32) => { impl_as_primitive!(@fn u32 as_u32); };
+    (@as u64) => { impl_as_primitive!(@fn u64 as_u64); };
+    (@as u128) => { impl_as_primitive!(@fn u128 as_u128); };
+    (@as usize) => { impl_as_primitive!(@fn usize as_usize); };
+    (@as i8) => { impl_as_primitive!(@fn i8 as_i8); };
+    (@as i16) => { impl_as_primitive!(@fn i16 as_i16); };
+    (@as i32) => { impl_as_primitive!(@fn i32 as_i32); };
+    (@as i64) => { impl_as_primitive!(@fn i64 as_i64); };
+    (@as i128) => { impl_as_primitive!(@fn i128 as_i128); };
+    (@as isize) => { impl_as_primitive!(@fn isize as_isize); };
+    (@fn $into:ident $name:ident) => {
+        #[doc = concat!("Converts the bounded integer to a [`", stringify!($into), "`].")]
+        #[must_use]
+        #[inline]
+        pub const fn $name(self) -> $into {
+            self.get() as $into
+        }
+    };
+}
+
+macro_rules! impl_signed_diff {
+    (u8) => { impl_signed_diff!(@fn i16 BoundedI16); };
+    (u16) => { impl_signed_diff!(@fn i32 BoundedI32); };
+    (u32) => { impl_signed_diff!(@fn i64 BoundedI64); };
+    (u64) => { impl_signed_diff!(@fn i128 BoundedI128); };
+    (usize) => { impl_signed_diff!(@fn i128 BoundedI128); };
+    (i8) => { impl_signed_diff!(@fn i16 BoundedI16); };
+    (i16) => { impl_signed_diff!(@fn i32 BoundedI32); };
+    (i32) => { impl_signed_diff!(@fn i64 BoundedI64); };
+    (i64) => { impl_signed_diff!(@fn i128 BoundedI128); };
+    (isize) => { impl_signed_diff!(@fn i128 BoundedI128); };
+    // There is no wider primitive to hold the difference.
+    (u128) => {};
+    (i128) => {};
+    (@fn $wide:ident $bounded:ident) => {
+        /// Returns `self - other` as a wider signed bounded integer in the range
+        /// `-(MAX - MIN)..=MAX - MIN`, which holds every possible difference. This differs from the
+        /// `signed_diff` of the types generated by the macro, which returns a primitive.
+        ///
+        /// Because the bounds of the result can't be computed from `MIN` and `MAX` on stable Rust,
+        /// they are given as the parameters `DMIN` and `DMAX`, which fail to compile unless they
+        /// are `-(MAX - MIN)` and `MAX - MIN`. They can usually be inferred from the type the
+        /// result is assigned to.
+        #[must_use]
+        #[inline]
+        pub const fn signed_diff_bounded<const DMIN: $wide, const DMAX: $wide>(
+            self,
+            other: Self,
+        ) -> crate::$bounded<DMIN, DMAX> {
+            const {
+                assert!(
+                    DMAX == MAX as $wide - MIN as $wide && DMIN == -DMAX,
+                    "bounds of the result must be -(MAX - MIN) and MAX - MIN",
+                );
+            };
+            // SAFETY: Both values are in `MIN..=MAX`, so their difference is in range.
+            unsafe { crate::$bounded::new_unchecked(self.get() as $wide - other.get() as $wide) }
+        }
+    };
+}
+
+macro_rules! impl_widening_mul {
+    (u8) => { impl_widening_mul!(@fn u16); };
+    (u16) => { impl_widening_mul!(@fn u32); };
+    (u32) => { impl_widening_mul!(@fn u64); };
+    (u64) => { impl_widening_mul!(@fn u128); };
+    (usize) => { impl_widening_mul!(@fn u128); };
+    (i8) => { impl_widening_mul!(@fn i16); };
+    (i16) => { impl_widening_mul!(@fn i32); };
+    (i32) => { impl_widening_mul!(@fn i64); };
+    (i64) => { impl_widening_mul!(@fn i128); };
+    (isize) => { impl_widening_mul!(@fn i128); };
+    // There is no wider primitive to hold the product.
+    (u128) => {};
+    (i128) => {};
+    (@fn $wide:ident) => {
+        /// Multiplies by another value of the same bounded integer type, returning the exact
+        /// product in a primitive twice as wide, which can never overflow.
+        #[must_use]
+        #[inline]
+        pub const fn widening_mul(self, rhs: Self) -> $wide {
+            self.get() as $wide * rhs.get() as $wide
+        }
+    };
+}
+
+/// Computes `$n * $mul / $div` for a primitive without the product overflowing, unless the
+/// primitive is 128 bits wide. Gives `None` if `$div` is zero or the quotient does not fit.
+macro_rules! mul_div {
+    (u128, $n:expr, $mul:expr, $div:expr) => { mul_div!(@narrow $n, $mul, $div) };
+    (i128, $n:expr, $mul:expr, $div:expr) => { mul_div!(@narrow $n, $mul, $div) };
+    (u8, $n:expr, $mul:expr, $div:expr) => { mul_div!(@wide u8 u128, $n, $mul, $div) };
+    (u16, $n:expr, $mul:expr, $div:expr) => { mul_div!(@wide u16 u128, $n, $mul, $div) };
+    (u32, $n:expr, $mul:expr, $div:expr) => { mul_div!(@wide u32 u128, $n, $mul, $div) };
+    (u64, $n:expr, $mul:expr, $div:expr) => { mul_div!(@wide u64 u128, $n, $mul, $div) };
+    (usize, $n:expr, $mul:expr, $div:expr) => { mul_div!(@wide usize u128, $n, $mul, $div) };
+    (i8, $n:expr, $mul:expr, $div:expr) => { mul_div!(@wide i8 i128, $n, $mul, $div) };
+    (i16, $n:expr, $mul:expr, $div:expr) => { mul_div!(@wide i16 i128, $n, $mul, $div) };
+    (i32, $n:expr, $mul:expr, $div:expr) => { mul_div!(@wide i32 i128, $n, $mul, $div) };
+    (i64, $n:expr, $mul:expr, $div:expr) => { mul_div!(@wide i64 i128, $n, $mul, $div) };
+    (isize, $n:expr, $mul:expr, $div:expr) => { mul_div!(@wide isize i128, $n, $mul, $div) };
+    (@narrow $n:expr, $mul:expr, $div:expr) => {
+        match $n.checked_mul($mul) {
+            Some(product) => product.checked_div($div),
+            None => None,
+        }
+    };
+    (@wide $inner:ident $wide:ident, $n:expr, $mul:expr, $div:expr) => {
+        if $div == 0 {
+            None
+        } else {
+            let n = $n as $wide * $mul as $wide / $div as $wide;
+            if n < $inner::MIN as $wide || n > $inner::MAX as $wide {
+                None
+            } else {
+                Some(n as $inner)
+            }
+        }
+    };
+}
+
+macro_rules! impl_saturating_step {
+    (u8) => { impl_saturating_step!(@fn i8 saturating_add_signed); };
+    (u16) => { impl_saturating_step!(@fn i16 saturating_add_signed); };
+    (u32) => { impl_saturating_step!(@fn i32 saturating_add_signed); };
+    (u64) => { impl_saturating_step!(@fn i64 saturating_add_signed); };
+    (u128) => { impl_saturating_step!(@fn i128 saturating_add_signed); };
+    (usize) => { impl_saturating_step!(@fn isize saturating_add_signed); };
+    (i8) => { impl_saturating_step!(@fn i8 saturating_add); };
+    (i16) => { impl_saturating_step!(@fn i16 saturating_add); };
+    (i32) => { impl_saturating_step!(@fn i32 saturating_add); };
+    (i64) => { impl_saturating_step!(@fn i64 saturating_add); };
+    (i128) => { impl_saturating_step!(@fn i128 saturating_add); };
+    (isize) => { impl_saturating_step!(@fn isize saturating_add); };
+    (@fn $signed:ident $add:ident) => {
+        /// Moves the value by a signed step, saturating at [`MIN`](Self::MIN) and
+        /// [`MAX`](Self::MAX). This suits sliders and clamped counters.
+        #[must_use]
+        #[inline]
+        pub const fn saturating_step(self, n: $signed) -> Self {
+            Self::new_saturating(self.get().$add(n))
+        }
+    };
+}
+
+macro_rules! sign_magnitude {
+    ($value:expr) => {
+        (false, $value as u128)
+    };
+    ($value:expr, signed) => {
+        ($value < 0, $value.unsigned_abs() as u128)
+    };
+}
+
 macro_rules! impl_fmt_traits {
     ($($trait:ident),*) => { $(
         impl<const MIN: Inner, const MAX: Inner> fmt::$trait for Bounded<MIN, MAX> {
@@ -141,14 +302,21 @@ macro_rules! impl_fmt_traits {
 
 macro_rules! define_bounded_integers {
     ($(
-        $name:ident $inner:ident $(signed $([$signed:ident])?)? -> $($into:ident)*,
+        $name:ident $inner:ident $unsigned:ident
+        $(signed $([$signed:ident])?)? $(unsigned $([$unsigned_only:ident])?)? -> $($into:ident)*,
     )*) => { $( mod $inner {
         use core::borrow::Borrow;
         use core::cmp;
         use core::fmt;
+        use core::hash::{Hash, Hasher};
         use core::iter;
+        use core::ops::RangeInclusive;
+        use core::str;
+
+        use crate::ParseError;
 
         type Inner = core::primitive::$inner;
+        type Unsigned = core::primitive::$unsigned;
 
         calculated_doc! {
             /// An
@@ -157,7 +325,11 @@ macro_rules! define_bounded_integers {
             -
             #[cfg_attr(doc_cfg, doc(cfg(feature = "types")))]
             #[repr(transparent)]
-            #[derive(Debug, Hash, Clone, Copy, Eq, Ord)]
+            #[derive(Debug, Clone, Copy, Eq, Ord)]
+            #[cfg_attr(
+                feature = "zerocopy",
+                derive(zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout),
+            )]
             pub struct Bounded<const MIN: Inner, const MAX: Inner> (Inner);
         }
 
@@ -166,12 +338,25 @@ macro_rules! define_bounded_integers {
             pub const MIN_VALUE: Inner = MIN;
             /// The largest value that this bounded integer can contain.
             pub const MAX_VALUE: Inner = MAX;
+            /// The range of values that this bounded integer can contain. As it implements
+            /// [`RangeBounds`](core::ops::RangeBounds), it can be passed to APIs expecting a range.
+            pub const RANGE: RangeInclusive<Inner> = MIN..=MAX;
 
             /// The smallest value of the bounded integer.
             pub const MIN: Self = Self(MIN);
             /// The largest value of the bounded integer.
             pub const MAX: Self = Self(MAX);
 
+            /// The number of bits needed to distinguish every value in the range, for packing
+            /// bounded integers into bitfields. This is the number of bits in the largest
+            /// [`to_index`](Self::to_index), not the width of the underlying integer.
+            pub const SIZE_BITS: u32 =
+                Unsigned::BITS - (MAX.wrapping_sub(MIN) as Unsigned).leading_zeros();
+
+            /// The number of bits in the underlying integer, unlike [`SIZE_BITS`](Self::SIZE_BITS)
+            /// which only counts the bits needed by the range.
+            pub const REPR_BITS: u32 = Inner::BITS;
+
             /// Creates a bounded integer without checking the value.
             ///
             /// # Safety
@@ -211,6 +396,18 @@ macro_rules! define_bounded_integers {
                 &mut *<*mut _>::cast(n)
             }
 
+            /// Creates a slice of bounded integers from a slice of primitives.
+            ///
+            /// # Safety
+            ///
+            /// Every value must be within the range; none may be less than
+            /// [`MIN_VALUE`](Self::MIN_VALUE) or greater than [`MAX_VALUE`](Self::MAX_VALUE).
+            #[must_use]
+            pub unsafe fn from_slice_unchecked(s: &[Inner]) -> &[Self] {
+                debug_assert!(Self::validate_slice(s).is_ok());
+                &*(s as *const [Inner] as *const [Self])
+            }
+
             /// Checks whether the given value is in the range of the bounded integer.
             #[must_use]
             #[inline]
@@ -218,6 +415,20 @@ macro_rules! define_bounded_integers {
                 n >= Self::MIN_VALUE && n <= Self::MAX_VALUE
             }
 
+            /// Clamps the given value to the range of the bounded integer, without creating a
+            /// bounded integer.
+            #[must_use]
+            #[inline]
+            pub const fn clamp_repr(n: Inner) -> Inner {
+                if n < Self::MIN_VALUE {
+                    Self::MIN_VALUE
+                } else if n > Self::MAX_VALUE {
+                    Self::MAX_VALUE
+                } else {
+                    n
+                }
+            }
+
             /// Creates a bounded integer if the given value is within the range
             /// [[`MIN`](Self::MIN), [`MAX`](Self::MAX)].
             #[must_use]
@@ -230,6 +441,20 @@ macro_rules! define_bounded_integers {
                 }
             }
 
+            /// Creates a bounded integer from a constant, failing to compile if the value is
+            /// outside the range [[`MIN`](Self::MIN), [`MAX`](Self::MAX)].
+            ///
+            /// ```compile_fail
+            #[doc = concat!("# use bounded_integer::", stringify!($name), ";")]
+            #[doc = concat!("let _ = ", stringify!($name), "::<0, 7>::new_const::<8>();")]
+            /// ```
+            #[must_use]
+            #[inline]
+            pub const fn new_const<const N: Inner>() -> Self {
+                const { assert!(Self::in_range(N), "constant out of range") };
+                Self(N)
+            }
+
             /// Creates a reference to a bounded integer from a reference to a primitive if the
             /// given value is within the range [[`MIN`](Self::MIN), [`MAX`](Self::MAX)].
             #[must_use]
@@ -253,6 +478,37 @@ macro_rules! define_bounded_integers {
                 })
             }
 
+            /// Checks that every value of the slice is within the range
+            /// [[`MIN`](Self::MIN), [`MAX`](Self::MAX)].
+            ///
+            /// # Errors
+            ///
+            /// Fails with the index and value of the first element that is out of range.
+            #[inline]
+            pub const fn validate_slice(s: &[Inner]) -> Result<(), (usize, Inner)> {
+                let mut i = 0;
+                while i < s.len() {
+                    if !Self::in_range(s[i]) {
+                        return Err((i, s[i]));
+                    }
+                    i += 1;
+                }
+                Ok(())
+            }
+
+            /// Creates a slice of bounded integers from a slice of primitives, without copying
+            /// it.
+            ///
+            /// # Errors
+            ///
+            /// Fails with the index and value of the first element that is out of range.
+            #[inline]
+            pub fn from_slice(s: &[Inner]) -> Result<&[Self], (usize, Inner)> {
+                Self::validate_slice(s)?;
+                // SAFETY: We just checked that every value is in range.
+                Ok(unsafe { Self::from_slice_unchecked(s) })
+            }
+
             /// Creates a bounded integer by setting the value to [`MIN`](Self::MIN) or
             /// [`MAX`](Self::MAX) if it is too low or too high respectively.
             #[must_use]
@@ -267,6 +523,121 @@ macro_rules! define_bounded_integers {
                 }
             }
 
+            /// Creates a bounded integer if the given value is within the range
+            /// [[`MIN`](Self::MIN), [`MAX`](Self::MAX)], falling back to `default` otherwise.
+            #[must_use]
+            #[inline]
+            pub const fn new_or(value: Inner, default: Self) -> Self {
+                match Self::new(value) {
+                    Some(bounded) => bounded,
+                    None => default,
+                }
+            }
+
+            /// Creates an array of bounded integers if every value of the given array is within
+            /// the range [[`MIN`](Self::MIN), [`MAX`](Self::MAX)], returning [`None`] if any is
+            /// not.
+            #[must_use]
+            #[inline]
+            pub fn new_array<const N: usize>(values: [Inner; N]) -> Option<[Self; N]> {
+                if values.iter().all(|&n| Self::in_range(n)) {
+                    Some(values.map(Self))
+                } else {
+                    None
+                }
+            }
+
+            /// Parses a bounded integer from an optionally signed ASCII decimal byte slice, without
+            /// requiring it to be valid UTF-8.
+            ///
+            /// # Errors
+            ///
+            /// Fails if the bytes are not a decimal integer or it is out of range.
+            pub fn from_ascii(bytes: &[u8]) -> Result<Self, ParseError> {
+                let (negative, magnitude) = crate::parse::from_ascii(
+                    bytes,
+                    sign_magnitude!(MIN $($(if $signed)?, signed)?),
+                    sign_magnitude!(MAX $($(if $signed)?, signed)?),
+                )?;
+                let n = if negative {
+                    (magnitude as Inner).wrapping_neg()
+                } else {
+                    magnitude as Inner
+                };
+                // SAFETY: The value was checked to be within the range.
+                Ok(unsafe { Self::new_unchecked(n) })
+            }
+
+            /// Creates a bounded integer from an `f32`. Returns [`None`] if the float is NaN,
+            /// infinite, has a fractional part or is out of range.
+            ///
+            /// An `f32` has only 24 bits of precision, so for wide ranges not every integer can be
+            /// given exactly: the literal `16_777_217.0` is already rounded to `16_777_216.0`
+            /// before it is passed in.
+            #[must_use]
+            #[inline]
+            pub const fn checked_from_f32(f: f32) -> Option<Self> {
+                // Casting saturates, so a float past the end of the primitive would otherwise
+                // round-trip through its maximum. Since the maximum is odd, this is exactly one
+                // more than it.
+                let limit = ((Inner::MAX >> 1) + 1) as f32 * 2.0;
+                let n = f as Inner;
+                let round_trip = n as f32;
+                if f.is_nan() || round_trip < f || round_trip > f || f >= limit {
+                    return None;
+                }
+                Self::new(n)
+            }
+
+            /// Creates a bounded integer from an `f32`, truncating it towards zero and saturating
+            /// it to the range. NaN is treated as zero.
+            ///
+            /// As with [`checked_from_f32`](Self::checked_from_f32), large values may have
+            /// already lost precision.
+            #[must_use]
+            #[inline]
+            pub const fn from_f32_saturating(f: f32) -> Self {
+                Self::new_saturating(f as Inner)
+            }
+
+            /// Converts the value to an `f32`, rounding it to the nearest representable float.
+            ///
+            /// An `f32` has only 24 bits of precision, so this is only exact for values up to
+            /// 2<sup>24</sup> in magnitude.
+            #[must_use]
+            #[inline]
+            pub const fn to_f32(self) -> f32 {
+                self.0 as f32
+            }
+
+            /// Converts the value to an `f64`, rounding it to the nearest representable float.
+            ///
+            /// An `f64` has only 53 bits of precision, so this is only exact for values up to
+            /// 2<sup>53</sup> in magnitude.
+            #[must_use]
+            #[inline]
+            pub const fn to_f64(self) -> f64 {
+                self.0 as f64
+            }
+
+            /// Creates an array of bounded integers from an iterator of primitives, for building
+            /// fixed-size tables from raw data. Returns [`None`] if any of the values is out of
+            /// range or the iterator does not yield exactly `N` values.
+            #[must_use]
+            pub fn try_collect_into_array<const N: usize>(
+                iter: impl IntoIterator<Item = Inner>,
+            ) -> Option<[Self; N]> {
+                let mut iter = iter.into_iter();
+                let mut array = [Self::MIN; N];
+                for slot in &mut array {
+                    *slot = Self::new(iter.next()?)?;
+                }
+                match iter.next() {
+                    Some(_) => None,
+                    None => Some(array),
+                }
+            }
+
             /// Returns the value of the bounded integer as a primitive type.
             #[must_use]
             #[inline]
@@ -274,6 +645,14 @@ macro_rules! define_bounded_integers {
                 self.0
             }
 
+            /// Mixes the value into a 64-bit seed for deterministic hashing or sharding. The seed
+            /// is the same on every run and platform, and depends only on the value.
+            #[must_use]
+            #[inline]
+            pub const fn hash_seed(self) -> u64 {
+                crate::hash_seed::hash_seed(self.0 as u128)
+            }
+
             /// Returns a shared reference to the value of the bounded integer.
             #[must_use]
             #[inline]
@@ -281,6 +660,35 @@ macro_rules! define_bounded_integers {
                 &self.0
             }
 
+            /// Returns a shared reference to the value of the bounded integer, like
+            /// [`get_ref`](Self::get_ref).
+            ///
+            /// # Safety
+            ///
+            /// There are no requirements beyond those of the safe `get_ref`: the reference cannot
+            /// be used to change the value, so it is always in range. This exists for code that
+            /// mirrors [`get_mut`](Self::get_mut) and wants both borrows to look alike; prefer
+            /// `get_ref` everywhere else.
+            #[must_use]
+            #[inline]
+            pub const unsafe fn get_unchecked_ref(&self) -> &Inner {
+                self.get_ref()
+            }
+
+            /// Compares the value of the bounded integer to a primitive. Unlike [`Ord`], this can
+            /// be used in const contexts.
+            #[must_use]
+            #[inline]
+            pub const fn cmp_value(self, v: Inner) -> cmp::Ordering {
+                if self.0 < v {
+                    cmp::Ordering::Less
+                } else if self.0 > v {
+                    cmp::Ordering::Greater
+                } else {
+                    cmp::Ordering::Equal
+                }
+            }
+
             /// Returns a mutable reference to the value of the bounded integer.
             ///
             /// # Safety
@@ -292,6 +700,188 @@ macro_rules! define_bounded_integers {
                 &mut *<*mut _>::cast(self)
             }
 
+            impl_as_primitive!($($into)*);
+            impl_signed_diff!($inner);
+            impl_saturating_step!($inner);
+            impl_widening_mul!($inner);
+
+            /// Converts the bounded integer to a string in decimal, without going through the
+            /// [`Display`](fmt::Display) machinery.
+            #[cfg(feature = "alloc")]
+            #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+            #[must_use]
+            pub fn to_string(&self) -> alloc::string::String {
+                let (negative, magnitude) = sign_magnitude!(self.get() $($(if $signed)?, signed)?);
+                crate::to_string::to_string(negative, magnitude)
+            }
+
+            /// Reads a bounded integer from bytes, like zerocopy's
+            /// [`TryFromBytes::try_read_from_bytes`](zerocopy::TryFromBytes::try_read_from_bytes).
+            /// Returns `None` if `bytes` has the wrong length or holds a value outside the range
+            /// [[`MIN`](Self::MIN), [`MAX`](Self::MAX)].
+            #[cfg(feature = "zerocopy")]
+            #[cfg_attr(doc_cfg, doc(cfg(feature = "zerocopy")))]
+            #[must_use]
+            #[inline]
+            pub fn try_read_from_bytes(bytes: &[u8]) -> Option<Self> {
+                Self::new(<Inner as zerocopy::FromBytes>::read_from_bytes(bytes).ok()?)
+            }
+
+            /// Interprets bytes as a reference to a bounded integer, like zerocopy's
+            /// [`TryFromBytes::try_ref_from_bytes`](zerocopy::TryFromBytes::try_ref_from_bytes).
+            /// Returns `None` if `bytes` has the wrong length or alignment or holds a value outside
+            /// the range [[`MIN`](Self::MIN), [`MAX`](Self::MAX)].
+            #[cfg(feature = "zerocopy")]
+            #[cfg_attr(doc_cfg, doc(cfg(feature = "zerocopy")))]
+            #[must_use]
+            #[inline]
+            pub fn try_ref_from_bytes(bytes: &[u8]) -> Option<&Self> {
+                Self::new_ref(<Inner as zerocopy::FromBytes>::ref_from_bytes(bytes).ok()?)
+            }
+
+            /// Interprets bytes as a mutable reference to a bounded integer, like zerocopy's
+            /// [`TryFromBytes::try_mut_from_bytes`](zerocopy::TryFromBytes::try_mut_from_bytes).
+            /// Returns `None` if `bytes` has the wrong length or alignment or holds a value outside
+            /// the range [[`MIN`](Self::MIN), [`MAX`](Self::MAX)].
+            #[cfg(feature = "zerocopy")]
+            #[cfg_attr(doc_cfg, doc(cfg(feature = "zerocopy")))]
+            #[must_use]
+            #[inline]
+            pub fn try_mut_from_bytes(bytes: &mut [u8]) -> Option<&mut Self> {
+                Self::new_mut(<Inner as zerocopy::FromBytes>::mut_from_bytes(bytes).ok()?)
+            }
+
+            /// Returns the distance forward from `self` to `other`, wrapping around from
+            /// [`MAX`](Self::MAX) to [`MIN`](Self::MIN) if `other` is less than `self`.
+            #[must_use]
+            #[inline]
+            pub const fn wrapping_distance(self, other: Self) -> Unsigned {
+                if self.get() <= other.get() {
+                    other.get().wrapping_sub(self.get()) as Unsigned
+                } else {
+                    // Split the distance at the wrapping point so that it cannot overflow.
+                    MAX.wrapping_sub(self.get()) as Unsigned
+                        + other.get().wrapping_sub(MIN) as Unsigned
+                        + 1
+                }
+            }
+
+            /// The number of values in the range, for declaring an array of type
+            /// `[T; Self::INDEX_LEN]` that [`to_index`](Self::to_index) can always index into.
+            ///
+            /// Using this constant fails to compile if the number doesn't fit in a [`usize`].
+            pub const INDEX_LEN: usize = {
+                let max_index = MAX.wrapping_sub(MIN) as Unsigned as u128;
+                assert!(
+                    max_index < usize::MAX as u128,
+                    "the range has too many values to index an array",
+                );
+                max_index as usize + 1
+            };
+
+            /// Returns the zero-based offset of the value from [`MIN`](Self::MIN), for indexing
+            /// into an array that has one element for each value in the range. It is always less
+            /// than [`INDEX_LEN`](Self::INDEX_LEN).
+            ///
+            /// The offset is truncated if it does not fit in a [`usize`].
+            #[must_use]
+            #[inline]
+            pub const fn to_index(self) -> usize {
+                self.get().wrapping_sub(MIN) as Unsigned as usize
+            }
+
+            /// Creates a bounded integer from its zero-based offset from [`MIN`](Self::MIN), the
+            /// inverse of [`to_index`](Self::to_index). Returns [`None`] if the index is beyond the
+            /// range.
+            #[must_use]
+            #[inline]
+            pub const fn from_index(index: usize) -> Option<Self> {
+                if index as u128 > MAX.wrapping_sub(MIN) as Unsigned as u128 {
+                    return None;
+                }
+                Self::new(MIN.wrapping_add(index as Inner))
+            }
+
+            /// Returns the position of the value in the range as a float from `0.0` at
+            /// [`MIN`](Self::MIN) to `1.0` at [`MAX`](Self::MAX). If the range has only one
+            /// value, this is always `0.0`.
+            #[must_use]
+            #[inline]
+            pub const fn normalized(self) -> f64 {
+                let span = MAX.wrapping_sub(MIN) as Unsigned;
+                if span == 0 {
+                    return 0.0;
+                }
+                self.0.wrapping_sub(MIN) as Unsigned as f64 / span as f64
+            }
+
+            /// Creates a bounded integer from its position in the range, the inverse of
+            /// [`normalized`](Self::normalized). `0.0` gives [`MIN`](Self::MIN), `1.0` gives
+            /// [`MAX`](Self::MAX), and values in between are rounded to the nearest integer.
+            /// Returns [`None`] if `t` is NaN or outside `0.0..=1.0`.
+            #[must_use]
+            #[inline]
+            pub const fn from_normalized(t: f64) -> Option<Self> {
+                if t.is_nan() || t < 0.0 || t > 1.0 {
+                    return None;
+                }
+                let span = MAX.wrapping_sub(MIN) as Unsigned;
+                // Converting a wide span to a float can round it up, so clamp the offset back
+                // into the range.
+                let mut offset = (t * span as f64 + 0.5) as Unsigned;
+                if offset > span {
+                    offset = span;
+                }
+                Some(Self(MIN.wrapping_add(offset as Inner)))
+            }
+
+            /// Stores the value's [`to_index`](Self::to_index) in the
+            /// [`SIZE_BITS`](Self::SIZE_BITS) bits of `word` starting at bit `shift`, leaving the
+            /// other bits unchanged.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the bit field does not fit in a `u64`.
+            #[must_use]
+            #[inline]
+            pub const fn pack_into(self, word: u64, shift: u32) -> u64 {
+                assert!(
+                    shift <= 64 && Self::SIZE_BITS <= 64 - shift,
+                    "bit field does not fit in the word",
+                );
+                if Self::SIZE_BITS == 0 {
+                    return word;
+                }
+                let mask = u64::MAX >> (64 - Self::SIZE_BITS);
+                let offset = self.get().wrapping_sub(MIN) as Unsigned;
+                (word & !(mask << shift)) | ((offset as u64) << shift)
+            }
+
+            /// Reads a value stored by [`pack_into`](Self::pack_into) from the
+            /// [`SIZE_BITS`](Self::SIZE_BITS) bits of `word` starting at bit `shift`. Returns
+            /// [`None`] if the bits don't hold a value in the range.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the bit field does not fit in a `u64`.
+            #[must_use]
+            #[inline]
+            pub const fn unpack_from(word: u64, shift: u32) -> Option<Self> {
+                assert!(
+                    shift <= 64 && Self::SIZE_BITS <= 64 - shift,
+                    "bit field does not fit in the word",
+                );
+                if Self::SIZE_BITS == 0 {
+                    return Some(Self::MIN);
+                }
+                let mask = u64::MAX >> (64 - Self::SIZE_BITS);
+                let offset = (word >> shift) & mask;
+                if offset as u128 > MAX.wrapping_sub(MIN) as Unsigned as u128 {
+                    return None;
+                }
+                Self::new(MIN.wrapping_add(offset as Inner))
+            }
+
             $($(if $signed)?
                 /// Computes the absolute value of `self`, panicking if it is out of range.
                 #[must_use]
@@ -299,6 +889,65 @@ macro_rules! define_bounded_integers {
                 pub fn abs(self) -> Self {
                     Self::new(self.get().abs()).expect("Absolute value out of range")
                 }
+
+                /// Returns a number representing the sign of `self`: `-1` if it is negative, `0`
+                /// if it is zero and `1` if it is positive.
+                #[must_use]
+                #[inline]
+                pub const fn signum(self) -> Inner {
+                    self.get().signum()
+                }
+            )*
+
+            /// Returns `true` if the value is even.
+            #[must_use]
+            #[inline]
+            pub const fn is_even(self) -> bool {
+                self.get() % 2 == 0
+            }
+
+            /// Returns `true` if the value is odd.
+            #[must_use]
+            #[inline]
+            pub const fn is_odd(self) -> bool {
+                !self.is_even()
+            }
+
+            /// Rotates the bits of the underlying integer left by `n` bits. Returns [`None`] if the
+            /// result is out of range.
+            #[must_use]
+            #[inline]
+            pub const fn rotate_left(self, n: u32) -> Option<Self> {
+                Self::new(self.get().rotate_left(n))
+            }
+
+            /// Rotates the bits of the underlying integer right by `n` bits. Returns [`None`] if
+            /// the result is out of range.
+            #[must_use]
+            #[inline]
+            pub const fn rotate_right(self, n: u32) -> Option<Self> {
+                Self::new(self.get().rotate_right(n))
+            }
+
+            $($(if $unsigned_only)?
+                /// Returns the smallest power of two greater than or equal to `self`. Returns
+                /// [`None`] if it overflows the underlying integer or is out of range.
+                #[must_use]
+                #[inline]
+                pub const fn checked_next_power_of_two(self) -> Option<Self> {
+                    match self.get().checked_next_power_of_two() {
+                        Some(val) => Self::new(val),
+                        None => None,
+                    }
+                }
+
+                /// Returns the integer square root of `self`, rounded down. Returns [`None`] if it
+                /// is below the range, which can only happen when [`MIN`](Self::MIN) is above 1.
+                #[must_use]
+                #[inline]
+                pub const fn checked_isqrt(self) -> Option<Self> {
+                    Self::new(self.get().isqrt())
+                }
             )*
 
             /// Raises `self` to the power of `exp`, using exponentiation by squaring. Panics if it
@@ -326,6 +975,73 @@ macro_rules! define_bounded_integers {
                     .expect("Attempted to divide with remainder out of range")
             }
 
+            /// Calculates the least nonnegative remainder of `self (mod N)` as a bounded integer in
+            /// the range `0..=N - 1`.
+            ///
+            /// Because the maximum of the result can't be computed from `N` on stable Rust, it is
+            /// given as the second parameter `R`, which fails to compile unless it is `N - 1`.
+            /// It can usually be inferred with `_`:
+            ///
+            /// ```
+            #[doc = concat!("# use bounded_integer::", stringify!($name), ";")]
+            #[doc = concat!("let n = ", stringify!($name), "::<0, 100>::new(27).unwrap();")]
+            #[doc = concat!("let rem: ", stringify!($name), "<0, 9> = n.rem_euclid_const::<10, _>();")]
+            /// assert_eq!(rem, 7);
+            /// ```
+            #[must_use]
+            #[inline]
+            pub const fn rem_euclid_const<const N: Inner, const R: Inner>(self) -> Bounded<0, R> {
+                const {
+                    assert!(N > 0, "modulus must be positive");
+                    assert!(R == N - 1, "maximum of the result must be one less than the modulus");
+                };
+                Bounded(self.get().rem_euclid(N))
+            }
+
+            /// Converts the value to a bounded integer with another range, such as a narrower or
+            /// shifted one. Returns [`None`] if the value is outside the new range.
+            #[must_use]
+            #[inline]
+            pub const fn try_into_subrange<const NMIN: Inner, const NMAX: Inner>(
+                self,
+            ) -> Option<Bounded<NMIN, NMAX>> {
+                Bounded::new(self.get())
+            }
+
+            /// Calculates the least nonnegative remainder of `value (mod modulus)`, which is
+            /// always less than `modulus` and so at most `MAX - 1`. Fails to compile if the range
+            /// contains zero or negative values.
+            #[must_use]
+            #[inline]
+            pub const fn reduce(value: Inner, modulus: Self) -> Inner {
+                const { assert!(MIN > 0, "modulus must be positive") };
+                value.rem_euclid(modulus.get())
+            }
+
+            /// Returns the logarithm of `self` with respect to an arbitrary base, rounded down.
+            /// Returns [`None`] if `self` is not positive or `base` is less than 2.
+            #[must_use]
+            #[inline]
+            pub const fn checked_ilog(self, base: Inner) -> Option<u32> {
+                self.get().checked_ilog(base)
+            }
+
+            /// Returns the base 2 logarithm of `self`, rounded down. Returns [`None`] if `self` is
+            /// not positive.
+            #[must_use]
+            #[inline]
+            pub const fn checked_ilog2(self) -> Option<u32> {
+                self.get().checked_ilog2()
+            }
+
+            /// Returns the base 10 logarithm of `self`, rounded down. Returns [`None`] if `self` is
+            /// not positive.
+            #[must_use]
+            #[inline]
+            pub const fn checked_ilog10(self) -> Option<u32> {
+                self.get().checked_ilog10()
+            }
+
             /// Checked integer addition.
             #[must_use]
             #[inline]
@@ -453,27 +1169,216 @@ macro_rules! define_bounded_integers {
                 }
             )*
 
-            /// Checked exponentiation.
+            /// Checked bitwise not.
             #[must_use]
             #[inline]
-            pub const fn checked_pow(self, rhs: u32) -> Option<Self> {
-                match self.get().checked_pow(rhs) {
-                    Some(val) => Self::new(val),
-                    None => None,
-                }
+            pub const fn checked_not(self) -> Option<Self> {
+                Self::new(!self.get())
             }
 
-            /// Saturating exponentiation.
+            /// Checked increment by one.
             #[must_use]
             #[inline]
-            pub const fn saturating_pow(self, rhs: u32) -> Self {
-                Self::new_saturating(self.get().saturating_pow(rhs))
+            pub const fn checked_increment(self) -> Option<Self> {
+                self.checked_add(1)
             }
-        }
-
-        // === Operators ===
 
-        impl_bin_op!(Add::add/AddAssign::add_assign, "add");
+            /// Checked decrement by one.
+            #[must_use]
+            #[inline]
+            pub const fn checked_decrement(self) -> Option<Self> {
+                self.checked_sub(1)
+            }
+
+            /// Advances to the next value, wrapping from [`MAX`](Self::MAX) back to
+            /// [`MIN`](Self::MIN). This is useful for cycling through states.
+            #[must_use]
+            #[inline]
+            pub const fn cycle_next(self) -> Self {
+                match self.checked_increment() {
+                    Some(next) => next,
+                    None => Self::MIN,
+                }
+            }
+
+            /// Goes back to the previous value, wrapping from [`MIN`](Self::MIN) back to
+            /// [`MAX`](Self::MAX).
+            #[must_use]
+            #[inline]
+            pub const fn cycle_prev(self) -> Self {
+                match self.checked_decrement() {
+                    Some(prev) => prev,
+                    None => Self::MAX,
+                }
+            }
+
+            /// Checked addition of another value of the same bounded integer type.
+            #[must_use]
+            #[inline]
+            pub const fn checked_add_bounded(self, rhs: Self) -> Option<Self> {
+                self.checked_add(rhs.get())
+            }
+
+            /// Checked subtraction of another value of the same bounded integer type.
+            #[must_use]
+            #[inline]
+            pub const fn checked_sub_bounded(self, rhs: Self) -> Option<Self> {
+                self.checked_sub(rhs.get())
+            }
+
+            /// Checked multiplication by another value of the same bounded integer type.
+            #[must_use]
+            #[inline]
+            pub const fn checked_mul_bounded(self, rhs: Self) -> Option<Self> {
+                self.checked_mul(rhs.get())
+            }
+
+            /// Checked division by another value of the same bounded integer type. Returns
+            /// [`None`] if `rhs` is zero or the quotient is out of range.
+            #[must_use]
+            #[inline]
+            pub const fn checked_div_bounded(self, rhs: Self) -> Option<Self> {
+                self.checked_div(rhs.get())
+            }
+
+            /// Checked remainder by another value of the same bounded integer type. Returns
+            /// [`None`] if `rhs` is zero or the remainder is out of range.
+            #[must_use]
+            #[inline]
+            pub const fn checked_rem_bounded(self, rhs: Self) -> Option<Self> {
+                self.checked_rem(rhs.get())
+            }
+
+            /// Checked division rounding towards negative infinity. Returns [`None`] if `rhs` is
+            /// zero, the division overflows or the quotient is out of range.
+            #[must_use]
+            #[inline]
+            pub const fn checked_div_floor(self, rhs: Inner) -> Option<Self> {
+                let n = self.0;
+                let (quotient, remainder) = match (n.checked_div(rhs), n.checked_rem(rhs)) {
+                    (Some(q), Some(r)) => (q, r),
+                    _ => return None,
+                };
+                // Truncation rounded up if the exact quotient is negative and not whole.
+                if remainder != 0 && (remainder > 0) != (rhs > 0) {
+                    Self::new(quotient - 1)
+                } else {
+                    Self::new(quotient)
+                }
+            }
+
+            /// Checked division rounding towards positive infinity. Returns [`None`] if `rhs` is
+            /// zero, the division overflows or the quotient is out of range.
+            #[must_use]
+            #[inline]
+            pub const fn checked_div_ceil(self, rhs: Inner) -> Option<Self> {
+                let n = self.0;
+                let (quotient, remainder) = match (n.checked_div(rhs), n.checked_rem(rhs)) {
+                    (Some(q), Some(r)) => (q, r),
+                    _ => return None,
+                };
+                // Truncation rounded down if the exact quotient is positive and not whole.
+                if remainder != 0 && (remainder > 0) == (rhs > 0) {
+                    Self::new(quotient + 1)
+                } else {
+                    Self::new(quotient)
+                }
+            }
+
+            /// Computes `self * mul / div`, rounding towards zero. Returns [`None`] if `div` is
+            /// zero or the result is out of range.
+            ///
+            /// The product is computed in a 128-bit integer, so it cannot overflow unless the
+            /// type itself is 128 bits wide.
+            #[must_use]
+            #[inline]
+            pub const fn checked_mul_div(self, mul: Inner, div: Inner) -> Option<Self> {
+                match mul_div!($inner, self.0, mul, div) {
+                    Some(n) => Self::new(n),
+                    None => None,
+                }
+            }
+
+            /// Returns the smaller of the value and `v`. As `v` may be below the range, the result
+            /// saturates at [`MIN`](Self::MIN).
+            #[must_use]
+            #[inline]
+            pub const fn min_with(self, v: Inner) -> Self {
+                if self.0 <= v {
+                    self
+                } else {
+                    Self::new_saturating(v)
+                }
+            }
+
+            /// Returns the larger of the value and `v`. As `v` may be above the range, the result
+            /// saturates at [`MAX`](Self::MAX).
+            #[must_use]
+            #[inline]
+            pub const fn max_with(self, v: Inner) -> Self {
+                if self.0 >= v {
+                    self
+                } else {
+                    Self::new_saturating(v)
+                }
+            }
+
+            /// Returns the midpoint of `self` and `other`, rounded towards negative infinity.
+            /// This never overflows, and as it lies between two in-range values it is always in
+            /// range.
+            #[must_use]
+            #[inline]
+            pub const fn midpoint_with(self, other: Self) -> Self {
+                Self((self.0 & other.0) + ((self.0 ^ other.0) >> 1))
+            }
+
+            /// Sums the bounded integers, returning [`None`] if any of the partial sums is out of
+            /// range. The sum of no values is zero, so it is [`None`] if zero is out of range.
+            #[must_use]
+            pub fn try_sum(iter: impl IntoIterator<Item = Self>) -> Option<Self> {
+                let mut iter = iter.into_iter();
+                let first = match iter.next() {
+                    Some(first) => first,
+                    None => return Self::new(0),
+                };
+                iter.try_fold(first, |sum, n| sum.checked_add(n.get()))
+            }
+
+            /// Folds every value in the range, in ascending order, into an accumulator.
+            pub fn fold_values<T>(init: T, mut f: impl FnMut(T, Self) -> T) -> T {
+                let mut acc = init;
+                let mut n = MIN;
+                loop {
+                    // SAFETY: `n` stays between `MIN` and `MAX`.
+                    acc = f(acc, unsafe { Self::new_unchecked(n) });
+                    if n == MAX {
+                        return acc;
+                    }
+                    n += 1;
+                }
+            }
+
+            /// Checked exponentiation.
+            #[must_use]
+            #[inline]
+            pub const fn checked_pow(self, rhs: u32) -> Option<Self> {
+                match self.get().checked_pow(rhs) {
+                    Some(val) => Self::new(val),
+                    None => None,
+                }
+            }
+
+            /// Saturating exponentiation.
+            #[must_use]
+            #[inline]
+            pub const fn saturating_pow(self, rhs: u32) -> Self {
+                Self::new_saturating(self.get().saturating_pow(rhs))
+            }
+        }
+
+        // === Operators ===
+
+        impl_bin_op!(Add::add/AddAssign::add_assign, "add");
         impl_bin_op!(Sub::sub/SubAssign::sub_assign, "subtract");
         impl_bin_op!(Mul::mul/MulAssign::mul_assign, "multiply");
         impl_bin_op!(Div::div/DivAssign::div_assign, "divide");
@@ -499,6 +1404,24 @@ macro_rules! define_bounded_integers {
             }
         )?
 
+        // Bitwise not can only be infallible when every value of the primitive is in range.
+        use core::ops::Not;
+
+        impl Not for Bounded<{ Inner::MIN }, { Inner::MAX }> {
+            type Output = Self;
+            #[inline]
+            fn not(self) -> Self::Output {
+                Self(!self.get())
+            }
+        }
+        impl Not for &Bounded<{ Inner::MIN }, { Inner::MAX }> {
+            type Output = Bounded<{ Inner::MIN }, { Inner::MAX }>;
+            #[inline]
+            fn not(self) -> Self::Output {
+                !*self
+            }
+        }
+
         // === Comparisons ===
 
         impl<const MIN: Inner, const MAX: Inner> PartialEq<Inner> for Bounded<MIN, MAX> {
@@ -543,7 +1466,16 @@ macro_rules! define_bounded_integers {
             }
         }
 
-        // === AsRef, Borrow ===
+        // === Hash, AsRef, Borrow ===
+
+        // This must hash identically to the primitive for the `Borrow` implementation to be
+        // correct.
+        impl<const MIN: Inner, const MAX: Inner> Hash for Bounded<MIN, MAX> {
+            #[inline]
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.get().hash(state);
+            }
+        }
 
         impl<const MIN: Inner, const MAX: Inner> AsRef<Inner> for Bounded<MIN, MAX> {
             #[inline]
@@ -558,6 +1490,17 @@ macro_rules! define_bounded_integers {
             }
         }
 
+        // === Default ===
+
+        // Like the primitives and macro-generated types, this defaults to zero. A range without
+        // zero cannot be excluded with const generics, so it defaults to the value nearest to zero.
+        impl<const MIN: Inner, const MAX: Inner> Default for Bounded<MIN, MAX> {
+            #[inline]
+            fn default() -> Self {
+                Self::new_saturating(0)
+            }
+        }
+
         // === Iterator traits ===
 
         // Sum bounded to bounded
@@ -630,6 +1573,17 @@ macro_rules! define_bounded_integers {
 
         impl_fmt_traits!(Binary, Display, LowerExp, LowerHex, Octal, UpperExp, UpperHex);
 
+        // === Parsing ===
+
+        impl<const MIN: Inner, const MAX: Inner> str::FromStr for Bounded<MIN, MAX> {
+            type Err = ParseError;
+            /// Parses the bounded integer like the primitive does, accepting a leading `+` or `-`,
+            /// and then checks that it is in range.
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Self::from_ascii(s.as_bytes())
+            }
+        }
+
         // === Serde ===
 
         #[cfg(feature = "serde")]
@@ -656,6 +1610,28 @@ macro_rules! define_bounded_integers {
             }
         }
 
+        // === BoundedInteger ===
+
+        impl<const MIN: Inner, const MAX: Inner> crate::BoundedInteger for Bounded<MIN, MAX> {
+            type Repr = Inner;
+
+            const MIN_VALUE: Inner = MIN;
+            const MAX_VALUE: Inner = MAX;
+
+            #[inline]
+            fn new(n: Inner) -> Option<Self> {
+                Self::new(n)
+            }
+            #[inline]
+            fn new_saturating(n: Inner) -> Self {
+                Self::new_saturating(n)
+            }
+            #[inline]
+            fn get(self) -> Inner {
+                self.get()
+            }
+        }
+
         // === Conversions ===
 
         $(impl<const MIN: Inner, const MAX: Inner> From<Bounded<MIN, MAX>> for $into {
@@ -668,7 +1644,7 @@ macro_rules! define_bounded_integers {
 
         #[cfg(test)]
         mod tests {
-            use super::Inner;
+            use super::{Inner, Unsigned};
 
             #[test]
             fn range() {
@@ -684,6 +1660,35 @@ macro_rules! define_bounded_integers {
                 assert!(!Bounded::in_range(11));
             }
 
+            #[test]
+            fn new_const() {
+                type Bounded = super::Bounded<3, 10>;
+                const N: Bounded = Bounded::new_const::<7>();
+                assert_eq!(N.get(), 7);
+                assert_eq!(Bounded::new_const::<3>(), Bounded::MIN);
+                assert_eq!(Bounded::new_const::<10>(), Bounded::MAX);
+            }
+
+            #[test]
+            fn assert_in_range() {
+                type Bounded = super::Bounded<3, 10>;
+                const N: Bounded = crate::assert_in_range!(Bounded, 7);
+                assert_eq!(N.get(), 7);
+                assert_eq!(crate::assert_in_range!(Bounded, 10), Bounded::MAX);
+            }
+
+            #[test]
+            fn clamp_repr() {
+                type Bounded = super::Bounded<3, 10>;
+                assert_eq!(Bounded::clamp_repr(Inner::MIN), 3);
+                assert_eq!(Bounded::clamp_repr(2), 3);
+                assert_eq!(Bounded::clamp_repr(3), 3);
+                assert_eq!(Bounded::clamp_repr(7), 7);
+                assert_eq!(Bounded::clamp_repr(10), 10);
+                assert_eq!(Bounded::clamp_repr(11), 10);
+                assert_eq!(Bounded::clamp_repr(Inner::MAX), 10);
+            }
+
             #[test]
             fn saturating() {
                 type Bounded = super::Bounded<3, 10>;
@@ -725,6 +1730,684 @@ macro_rules! define_bounded_integers {
                 }
             }
 
+            #[test]
+            fn not() {
+                type Bounded = super::Bounded<{ Inner::MIN }, { Inner::MAX }>;
+                assert_eq!((!Bounded::new(5).unwrap()).get(), !5);
+                assert_eq!(!&Bounded::MIN, Bounded::MAX);
+            }
+
+            #[test]
+            fn checked_not() {
+                type Bounded = super::Bounded<{ Inner::MIN }, { Inner::MAX - 1 }>;
+                assert_eq!(Bounded::MIN.checked_not(), None);
+                assert_eq!(
+                    Bounded::new(Inner::MIN + 1).unwrap().checked_not(),
+                    Bounded::new(Inner::MAX - 1),
+                );
+            }
+
+            #[test]
+            fn checked_bounded() {
+                type Bounded = super::Bounded<3, 10>;
+                let b = |n| Bounded::new(n).unwrap();
+                assert_eq!(b(3).checked_add_bounded(b(7)), Some(b(10)));
+                assert_eq!(b(4).checked_add_bounded(b(7)), None);
+                assert_eq!(b(10).checked_sub_bounded(b(7)), Some(b(3)));
+                assert_eq!(b(9).checked_sub_bounded(b(7)), None);
+                assert_eq!(b(3).checked_mul_bounded(b(3)), Some(b(9)));
+                assert_eq!(b(4).checked_mul_bounded(b(3)), None);
+                assert_eq!(Bounded::MAX.checked_mul_bounded(Bounded::MAX), None);
+                assert_eq!(b(9).checked_div_bounded(b(3)), Some(b(3)));
+                assert_eq!(b(3).checked_div_bounded(b(3)), None);
+                let b = |n| super::Bounded::<0, 10>::new(n).unwrap();
+                assert_eq!(b(10).checked_div_bounded(b(0)), None);
+                assert_eq!(b(10).checked_div_bounded(b(3)), Some(b(3)));
+                assert_eq!(b(10).checked_rem_bounded(b(0)), None);
+                assert_eq!(b(10).checked_rem_bounded(b(3)), Some(b(1)));
+                let b = |n| super::Bounded::<3, 10>::new(n).unwrap();
+                assert_eq!(b(10).checked_rem_bounded(b(4)), None);
+                assert_eq!(b(10).checked_rem_bounded(b(7)), Some(b(3)));
+            }
+
+            #[test]
+            fn div_floor_ceil() {
+                type Bounded = super::Bounded<0, 20>;
+                let b = |n| Bounded::new(n).unwrap();
+                for n in 0..=20 {
+                    for d in 1..=7 {
+                        let floor = n / d;
+                        let ceil = if n % d == 0 { n / d } else { n / d + 1 };
+                        assert_eq!(b(n).checked_div_floor(d).map(Bounded::get), Some(floor));
+                        assert_eq!(b(n).checked_div_ceil(d).map(Bounded::get), Some(ceil));
+                    }
+                    assert_eq!(b(n).checked_div_floor(0), None);
+                    assert_eq!(b(n).checked_div_ceil(0), None);
+                }
+                // Only the quotient that is rounded the other way can be in range.
+                let b = |n| super::Bounded::<3, 10>::new(n).unwrap();
+                assert_eq!(b(10).checked_div_floor(4), None);
+                assert_eq!(b(10).checked_div_ceil(4), Some(b(3)));
+            }
+
+            #[test]
+            fn min_max_with() {
+                type Bounded = super::Bounded<3, 10>;
+                let b = |n| Bounded::new(n).unwrap();
+                assert_eq!(b(6).max_with(8), b(8));
+                assert_eq!(b(6).max_with(4), b(6));
+                assert_eq!(b(6).max_with(100), Bounded::MAX);
+                assert_eq!(b(6).min_with(4), b(4));
+                assert_eq!(b(6).min_with(8), b(6));
+                assert_eq!(b(6).min_with(0), Bounded::MIN);
+            }
+
+            #[test]
+            fn midpoint_with() {
+                let b = |n| super::Bounded::<3, 10>::new(n).unwrap();
+                assert_eq!(b(4).midpoint_with(b(8)), b(6));
+                assert_eq!(b(4).midpoint_with(b(7)), b(5));
+                assert_eq!(b(7).midpoint_with(b(4)), b(5));
+                assert_eq!(b(9).midpoint_with(b(9)), b(9));
+
+                // Adding the extremes would overflow the primitive.
+                type Full = super::Bounded<{ Inner::MIN }, { Inner::MAX }>;
+                let mid = Inner::MIN / 2 + Inner::MAX / 2;
+                assert_eq!(Full::MIN.midpoint_with(Full::MAX).get(), mid);
+                assert_eq!(Full::MAX.midpoint_with(Full::MIN).get(), mid);
+                assert_eq!(Full::MAX.midpoint_with(Full::MAX), Full::MAX);
+                assert_eq!(Full::MIN.midpoint_with(Full::MIN), Full::MIN);
+            }
+
+            #[test]
+            fn fold_values() {
+                type Bounded = super::Bounded<3, 10>;
+                let sum = Bounded::fold_values(0_u32, |sum, n| sum + n.get() as u32);
+                assert_eq!(sum, (3 + 10) * 8 / 2);
+                type Single = super::Bounded<{ Inner::MAX }, { Inner::MAX }>;
+                let count = Single::fold_values(0, |n, _| n + 1);
+                assert_eq!(count, 1);
+            }
+
+            #[test]
+            fn try_sum() {
+                type Bounded = super::Bounded<0, 10>;
+                let b = |n| Bounded::new(n).unwrap();
+                assert_eq!(Bounded::try_sum([b(1), b(2), b(3)]), Some(b(6)));
+                assert_eq!(Bounded::try_sum([b(10)]), Some(b(10)));
+                assert_eq!(Bounded::try_sum([]), Some(b(0)));
+                assert_eq!(Bounded::try_sum([b(5), b(5), b(1)]), None);
+
+                type Positive = super::Bounded<1, 10>;
+                assert_eq!(Positive::try_sum([]), None);
+            }
+
+            #[test]
+            fn from_str() {
+                use crate::ParseErrorKind;
+
+                type Bounded = super::Bounded<3, 10>;
+                assert_eq!("+5".parse::<Bounded>(), Ok(Bounded::new(5).unwrap()));
+                assert_eq!("5".parse::<Bounded>(), Ok(Bounded::new(5).unwrap()));
+                let kind = |s: &str| s.parse::<Bounded>().unwrap_err().kind();
+                assert_eq!(kind("-5"), ParseErrorKind::BelowMin);
+                assert_eq!(kind("11"), ParseErrorKind::AboveMax);
+                assert_eq!(kind("+"), ParseErrorKind::Empty);
+            }
+
+            #[test]
+            fn increment_decrement() {
+                type Bounded = super::Bounded<3, 10>;
+                assert_eq!(Bounded::MAX.checked_increment(), None);
+                assert_eq!(Bounded::MIN.checked_decrement(), None);
+                assert_eq!(Bounded::MIN.checked_increment(), Bounded::new(4));
+                assert_eq!(Bounded::MAX.checked_decrement(), Bounded::new(9));
+            }
+
+            #[test]
+            fn slices() {
+                type Bounded = super::Bounded<3, 10>;
+                let valid = [3, 7, 10];
+                assert_eq!(Bounded::validate_slice(&valid), Ok(()));
+                let bounded = Bounded::from_slice(&valid).unwrap();
+                assert_eq!(bounded, [Bounded::MIN, Bounded::new(7).unwrap(), Bounded::MAX]);
+                assert!(core::ptr::eq(bounded.as_ptr().cast::<Inner>(), valid.as_ptr()));
+                assert_eq!(Bounded::validate_slice(&[]), Ok(()));
+
+                let invalid = [3, 7, 11, 2];
+                assert_eq!(Bounded::validate_slice(&invalid), Err((2, 11)));
+                assert_eq!(Bounded::from_slice(&invalid), Err((2, 11)));
+                // SAFETY: The first two values are in range.
+                let prefix = unsafe { Bounded::from_slice_unchecked(&invalid[..2]) };
+                assert_eq!(prefix, &valid[..2]);
+            }
+
+            #[test]
+            fn new_or() {
+                type Bounded = super::Bounded<3, 10>;
+                const FALLBACK: Bounded = Bounded::new_or(11, Bounded::MIN);
+                assert_eq!(FALLBACK, Bounded::MIN);
+                assert_eq!(Bounded::new_or(2, Bounded::MAX), Bounded::MAX);
+                assert_eq!(Bounded::new_or(7, Bounded::MIN), 7);
+            }
+
+            #[test]
+            fn new_array() {
+                type Bounded = super::Bounded<3, 10>;
+                let values = Bounded::new_array([3, 7, 10]).unwrap();
+                assert_eq!(values, [Bounded::MIN, Bounded::new(7).unwrap(), Bounded::MAX]);
+                assert_eq!(Bounded::new_array([3, 11, 7]), None);
+                assert_eq!(Bounded::new_array([2]), None);
+                assert_eq!(Bounded::new_array([]), Some([]));
+            }
+
+            #[test]
+            fn checked_mul_div() {
+                type Bounded = super::Bounded<3, 10>;
+                let n = Bounded::new(9).unwrap();
+                assert_eq!(n.checked_mul_div(2, 3), Some(Bounded::new(6).unwrap()));
+                assert_eq!(n.checked_mul_div(10, 9), Some(Bounded::MAX));
+                assert_eq!(n.checked_mul_div(1, 0), None);
+                assert_eq!(n.checked_mul_div(2, 1), None);
+                assert_eq!(n.checked_mul_div(1, 9), None);
+            }
+
+            #[test]
+            fn saturating_step() {
+                type Bounded = super::Bounded<3, 10>;
+                const STEPPED: Bounded = Bounded::MIN.saturating_step(4);
+                assert_eq!(STEPPED, 7);
+                assert_eq!(STEPPED.saturating_step(-2), 5);
+                assert_eq!(STEPPED.saturating_step(100), Bounded::MAX);
+                assert_eq!(STEPPED.saturating_step(-100), Bounded::MIN);
+                assert_eq!(STEPPED.saturating_step(0), STEPPED);
+
+                type Full = super::Bounded<{ Inner::MIN }, { Inner::MAX }>;
+                assert_eq!(Full::MAX.saturating_step(1), Full::MAX);
+                assert_eq!(Full::MIN.saturating_step(-1), Full::MIN);
+            }
+
+            #[test]
+            fn cycle() {
+                type Bounded = super::Bounded<3, 10>;
+                const WRAPPED: Bounded = Bounded::MAX.cycle_next();
+                assert_eq!(WRAPPED, Bounded::MIN);
+                assert_eq!(Bounded::MIN.cycle_prev(), Bounded::MAX);
+                assert_eq!(Bounded::MIN.cycle_next(), 4);
+                assert_eq!(Bounded::MAX.cycle_prev(), 9);
+
+                type Full = super::Bounded<{ Inner::MIN }, { Inner::MAX }>;
+                assert_eq!(Full::MAX.cycle_next(), Full::MIN);
+                assert_eq!(Full::MIN.cycle_prev(), Full::MAX);
+
+                type Single = super::Bounded<5, 5>;
+                assert_eq!(Single::MIN.cycle_next(), Single::MIN);
+            }
+
+            #[test]
+            fn wrapping_distance() {
+                type Clock = super::Bounded<0, 11>;
+                let c = |n| Clock::new(n).unwrap();
+                assert_eq!(c(10).wrapping_distance(c(2)), 4);
+                assert_eq!(c(2).wrapping_distance(c(10)), 8);
+                assert_eq!(c(5).wrapping_distance(c(5)), 0);
+                assert_eq!(Clock::MAX.wrapping_distance(Clock::MIN), 1);
+
+                type Full = super::Bounded<{ Inner::MIN }, { Inner::MAX }>;
+                assert_eq!(Full::MAX.wrapping_distance(Full::MIN), 1);
+                assert_eq!(Full::MIN.wrapping_distance(Full::MAX), Unsigned::MAX);
+            }
+
+            #[test]
+            fn reduce() {
+                type Modulus = super::Bounded<1, 12>;
+                for m in 1..=12 {
+                    let modulus = Modulus::new(m).unwrap();
+                    for value in [Inner::MIN, 0, 5, 11, 12, 13, 100, Inner::MAX] {
+                        assert!(Modulus::reduce(value, modulus) < m);
+                    }
+                }
+                assert_eq!(Modulus::reduce(14, Modulus::new(12).unwrap()), 2);
+                $($(if $signed)? assert_eq!(Modulus::reduce(-1, Modulus::new(12).unwrap()), 11);)?
+            }
+
+            #[test]
+            fn parity() {
+                type Bounded = super::Bounded<0, 10>;
+                const EVEN: bool = Bounded::MIN.is_even();
+                assert!(EVEN);
+                assert!(Bounded::new(7).unwrap().is_odd());
+                assert!(!Bounded::new(7).unwrap().is_even());
+                assert!(Bounded::MAX.is_even());
+                $($(if $signed)?
+                    type Signed = super::Bounded<-5, 5>;
+                    assert!(Signed::MIN.is_odd());
+                    assert!(Signed::new(-4).unwrap().is_even());
+                    assert!(!Signed::new(-1).unwrap().is_even());
+                )?
+            }
+
+            $($(if $signed)?
+                #[test]
+                fn saturating_abs() {
+                    type Bounded = super::Bounded<-10, 5>;
+                    const ABS: Bounded = Bounded::MIN.saturating_abs();
+                    assert_eq!(ABS, Bounded::MAX);
+                    assert_eq!(Bounded::new(-3).unwrap().saturating_abs(), 3);
+                    assert_eq!(Bounded::new(4).unwrap().saturating_abs(), 4);
+
+                    type Full = super::Bounded<{ Inner::MIN }, { Inner::MAX }>;
+                    assert_eq!(Full::MIN.saturating_abs(), Inner::MAX);
+                    assert_eq!(Full::MIN.checked_abs(), None);
+                }
+            )?
+
+            $($(if $signed)?
+                #[test]
+                fn signum() {
+                    type Bounded = super::Bounded<-5, 5>;
+                    assert_eq!(Bounded::MIN.signum(), -1);
+                    assert_eq!(Bounded::new(-1).unwrap().signum(), -1);
+                    assert_eq!(Bounded::new(0).unwrap().signum(), 0);
+                    assert_eq!(Bounded::new(1).unwrap().signum(), 1);
+                    assert_eq!(Bounded::MAX.signum(), 1);
+                }
+            )?
+
+            $($(if $unsigned_only)?
+                #[test]
+                fn checked_next_power_of_two() {
+                    type Bounded = super::Bounded<1, 100>;
+                    assert_eq!(Bounded::MIN.checked_next_power_of_two(), Some(Bounded::MIN));
+                    let n = Bounded::new(5).unwrap().checked_next_power_of_two();
+                    assert_eq!(n.map(Bounded::get), Some(8));
+                    assert_eq!(Bounded::new(64).unwrap().checked_next_power_of_two().unwrap(), 64);
+                    assert_eq!(Bounded::new(65).unwrap().checked_next_power_of_two(), None);
+                    let max = super::Bounded::<0, { Inner::MAX }>::MAX;
+                    assert_eq!(max.checked_next_power_of_two(), None);
+                }
+
+                #[test]
+                fn checked_isqrt() {
+                    type Bounded = super::Bounded<0, 100>;
+                    for n in 0..=100 {
+                        let root = Bounded::new(n).unwrap().checked_isqrt();
+                        assert_eq!(root.map(Bounded::get), Some(n.isqrt()));
+                    }
+                    type Full = super::Bounded<0, { Inner::MAX }>;
+                    assert_eq!(Full::MAX.checked_isqrt().unwrap(), Inner::MAX.isqrt());
+
+                    // The root of the minimum is below the range.
+                    type High = super::Bounded<50, 100>;
+                    assert_eq!(High::MIN.checked_isqrt(), None);
+                    assert_eq!(High::MAX.checked_isqrt(), None);
+                }
+            )?
+
+            #[test]
+            #[cfg(feature = "zerocopy")]
+            fn into_bytes() {
+                use zerocopy::IntoBytes;
+
+                let value = super::Bounded::<3, 10>::new(7).unwrap();
+                assert_eq!(value.as_bytes(), Inner::to_ne_bytes(7));
+            }
+
+            #[test]
+            #[cfg(feature = "zerocopy")]
+            fn try_from_bytes() {
+                use zerocopy::IntoBytes;
+                type Bounded = super::Bounded<3, 10>;
+
+                for n in 0..20 {
+                    let expected = Bounded::new(n);
+                    let mut primitive = n;
+                    let bytes = primitive.as_mut_bytes();
+                    assert_eq!(Bounded::try_read_from_bytes(bytes), expected);
+                    assert_eq!(Bounded::try_ref_from_bytes(bytes).copied(), expected);
+                    if let Some(value) = Bounded::try_mut_from_bytes(bytes) {
+                        *value = Bounded::MAX;
+                    }
+                    assert_eq!(primitive, if expected.is_some() { 10 } else { n });
+                }
+                assert_eq!(Bounded::try_read_from_bytes(&[]), None);
+            }
+
+            #[test]
+            fn get_ref() {
+                type Bounded = super::Bounded<3, 10>;
+                const MAX: &Inner = Bounded::MAX.get_ref();
+                assert_eq!(*MAX, 10);
+                let value = Bounded::new(5).unwrap();
+                let reference = value.get_ref();
+                assert_eq!(*reference, value.get());
+                assert!(core::ptr::eq(reference, &value as *const Bounded as *const Inner));
+                // SAFETY: `get_unchecked_ref` has no requirements beyond those of `get_ref`.
+                let unchecked = unsafe { value.get_unchecked_ref() };
+                assert!(core::ptr::eq(unchecked, reference));
+            }
+
+            #[test]
+            fn from_f32() {
+                type Bounded = super::Bounded<3, 10>;
+                assert_eq!(Bounded::checked_from_f32(5.0), Bounded::new(5));
+                assert_eq!(Bounded::checked_from_f32(10.0), Some(Bounded::MAX));
+                assert_eq!(Bounded::checked_from_f32(5.5), None);
+                assert_eq!(Bounded::checked_from_f32(11.0), None);
+                assert_eq!(Bounded::checked_from_f32(-3.0), None);
+                assert_eq!(Bounded::checked_from_f32(f32::NAN), None);
+                assert_eq!(Bounded::checked_from_f32(f32::INFINITY), None);
+
+                assert_eq!(Bounded::from_f32_saturating(5.9), Bounded::new(5).unwrap());
+                assert_eq!(Bounded::from_f32_saturating(-1.0e30), Bounded::MIN);
+                assert_eq!(Bounded::from_f32_saturating(f32::INFINITY), Bounded::MAX);
+                assert_eq!(Bounded::from_f32_saturating(f32::NAN), Bounded::MIN);
+
+                type Full = super::Bounded<{ Inner::MIN }, { Inner::MAX }>;
+                let past_max = ((Inner::MAX >> 1) + 1) as f32 * 2.0;
+                assert_eq!(Full::checked_from_f32(past_max), None);
+                assert_eq!(Full::checked_from_f32(f32::INFINITY), None);
+                assert_eq!(Full::checked_from_f32(f32::NEG_INFINITY), None);
+                assert_eq!(Full::from_f32_saturating(past_max), Full::MAX);
+                assert_eq!(Full::checked_from_f32(Inner::MIN as f32), Some(Full::MIN));
+            }
+
+            #[test]
+            fn normalized() {
+                type Bounded = super::Bounded<3, 11>;
+                assert_eq!(Bounded::MIN.normalized(), 0.0);
+                assert_eq!(Bounded::MAX.normalized(), 1.0);
+                assert_eq!(Bounded::new(7).unwrap().normalized(), 0.5);
+                assert_eq!(super::Bounded::<4, 4>::MIN.normalized(), 0.0);
+
+                type Full = super::Bounded<{ Inner::MIN }, { Inner::MAX }>;
+                assert_eq!(Full::MIN.normalized(), 0.0);
+                assert_eq!(Full::MAX.normalized(), 1.0);
+                let middle = Full::new(Inner::MIN / 2 + Inner::MAX / 2).unwrap();
+                assert!((middle.normalized() - 0.5).abs() < 0.01);
+            }
+
+            #[test]
+            fn from_normalized() {
+                type Bounded = super::Bounded<3, 11>;
+                const MIDDLE: Option<Bounded> = Bounded::from_normalized(0.5);
+                assert_eq!(MIDDLE, Bounded::new(7));
+                assert_eq!(Bounded::from_normalized(0.0), Some(Bounded::MIN));
+                assert_eq!(Bounded::from_normalized(1.0), Some(Bounded::MAX));
+                assert_eq!(Bounded::from_normalized(0.3), Bounded::new(5));
+                assert_eq!(Bounded::from_normalized(f64::NAN), None);
+                assert_eq!(Bounded::from_normalized(-0.1), None);
+                assert_eq!(Bounded::from_normalized(1.1), None);
+
+                type Full = super::Bounded<{ Inner::MIN }, { Inner::MAX }>;
+                assert_eq!(Full::from_normalized(0.0), Some(Full::MIN));
+                assert_eq!(Full::from_normalized(1.0), Some(Full::MAX));
+            }
+
+            #[test]
+            fn to_float() {
+                type Bounded = super::Bounded<3, 10>;
+                const SEVEN: f64 = Bounded::new_const::<7>().to_f64();
+                assert_eq!(SEVEN, 7.0);
+                assert_eq!(Bounded::MIN.to_f32(), 3.0);
+
+                type Full = super::Bounded<{ Inner::MIN }, { Inner::MAX }>;
+                assert_eq!(Full::MIN.to_f64(), Inner::MIN as f64);
+                assert_eq!(Full::MAX.to_f64(), Inner::MAX as f64);
+                assert_eq!(Full::MAX.to_f32(), Inner::MAX as f32);
+            }
+
+            #[test]
+            fn range_bounds() {
+                use core::ops::{Bound, RangeBounds};
+
+                type Bounded = super::Bounded<3, 10>;
+                assert_eq!(Bounded::RANGE.start_bound(), Bound::Included(&3));
+                assert_eq!(Bounded::RANGE.end_bound(), Bound::Included(&10));
+                assert!(RangeBounds::contains(&Bounded::RANGE, &3));
+                assert!(!RangeBounds::contains(&Bounded::RANGE, &11));
+            }
+
+            #[test]
+            fn default() {
+                assert_eq!(super::Bounded::<3, 7>::default(), 3);
+                assert_eq!(super::Bounded::<0, 7>::default(), 0);
+                $($(if $signed)?
+                    assert_eq!(super::Bounded::<-5, 5>::default(), 0);
+                    assert_eq!(super::Bounded::<-5, -2>::default(), -2);
+                )?
+            }
+
+            #[test]
+            fn hash_seed() {
+                type Bounded = super::Bounded<3, 10>;
+                let (a, b) = (Bounded::new(4).unwrap(), Bounded::new(5).unwrap());
+                const SEED: u64 = Bounded::MIN.hash_seed();
+                assert_eq!(SEED, Bounded::MIN.hash_seed());
+                assert_eq!(a.hash_seed(), a.hash_seed());
+                assert_ne!(a.hash_seed(), b.hash_seed());
+                // The seed doesn't depend on the primitive.
+                assert_eq!(a.hash_seed(), crate::BoundedU8::<0, 10>::new(4).unwrap().hash_seed());
+            }
+
+            #[test]
+            fn cmp_value() {
+                use core::cmp::Ordering;
+
+                type Bounded = super::Bounded<3, 10>;
+                const AT_MAX: bool = match Bounded::MAX.cmp_value(10) {
+                    Ordering::Equal => true,
+                    Ordering::Less | Ordering::Greater => false,
+                };
+                assert!(AT_MAX);
+                assert_eq!(Bounded::MIN.cmp_value(4), Ordering::Less);
+                assert_eq!(Bounded::MAX.cmp_value(9), Ordering::Greater);
+            }
+
+            #[test]
+            fn rotate() {
+                type Bounded = super::Bounded<0, 0x0F>;
+                let n = Bounded::new(0b0110).unwrap();
+                assert_eq!(n.rotate_left(1).unwrap(), 0b1100);
+                assert_eq!(n.rotate_right(1).unwrap(), 0b0011);
+                assert_eq!(n.rotate_left(Inner::BITS).unwrap(), n);
+                assert_eq!(n.rotate_left(3), None);
+                assert_eq!(n.rotate_right(2), None);
+            }
+
+            #[test]
+            fn rem_euclid_const() {
+                type Bounded = super::Bounded<0, 100>;
+                let rem = Bounded::new(27).unwrap().rem_euclid_const::<10, _>();
+                let _: super::Bounded<0, 9> = rem;
+                assert_eq!(rem.get(), 7);
+                assert_eq!(Bounded::MAX.rem_euclid_const::<7, 6>().get(), 2);
+                assert_eq!(Bounded::MIN.rem_euclid_const::<1, 0>().get(), 0);
+                $($(if $signed)?
+                    let rem = super::Bounded::<-5, 5>::MIN.rem_euclid_const::<3, 2>();
+                    assert_eq!(rem.get(), 1);
+                )?
+            }
+
+            #[test]
+            #[cfg(feature = "alloc")]
+            fn to_string() {
+                extern crate std;
+                use std::format;
+
+                type Full = super::Bounded<{ Inner::MIN }, { Inner::MAX }>;
+                for n in [Inner::MIN, Inner::MIN + 1, 0, 1, 9, 10, 99, 100, Inner::MAX] {
+                    let b = Full::new(n).unwrap();
+                    assert_eq!(b.to_string(), format!("{}", b));
+                }
+                $($(if $signed)? assert_eq!(Full::new(-37).unwrap().to_string(), "-37");)?
+            }
+
+            #[test]
+            fn ilog() {
+                type Bounded = super::Bounded<0, 100>;
+                for n in [1, 2, 7, 8, 10, 99, 100] {
+                    let b = Bounded::new(n).unwrap();
+                    assert_eq!(b.checked_ilog2(), Some(n.ilog2()));
+                    assert_eq!(b.checked_ilog10(), Some(n.ilog10()));
+                    assert_eq!(b.checked_ilog(3), Some(n.ilog(3)));
+                }
+                assert_eq!(Bounded::MIN.checked_ilog2(), None);
+                assert_eq!(Bounded::MIN.checked_ilog10(), None);
+                assert_eq!(Bounded::MIN.checked_ilog(3), None);
+                assert_eq!(Bounded::MAX.checked_ilog(1), None);
+            }
+
+            #[test]
+            fn to_index() {
+                type Bounded = super::Bounded<3, 10>;
+                assert_eq!(Bounded::MIN.to_index(), 0);
+                assert_eq!(Bounded::new(5).unwrap().to_index(), 2);
+                assert_eq!(Bounded::MAX.to_index(), 7);
+
+                type Full = super::Bounded<{ Inner::MIN }, { Inner::MAX }>;
+                assert_eq!(Full::MIN.to_index(), 0);
+
+                let mut table = [0; Bounded::INDEX_LEN];
+                for n in 3..=10 {
+                    table[Bounded::new(n).unwrap().to_index()] = n;
+                }
+                assert_eq!(table, [3, 4, 5, 6, 7, 8, 9, 10]);
+                assert_eq!(super::Bounded::<4, 4>::INDEX_LEN, 1);
+            }
+
+            #[test]
+            fn from_index() {
+                type Bounded = super::Bounded<3, 10>;
+                assert_eq!(Bounded::from_index(0), Some(Bounded::MIN));
+                assert_eq!(Bounded::from_index(7), Some(Bounded::MAX));
+                assert_eq!(Bounded::from_index(8), None);
+                assert_eq!(Bounded::from_index(usize::MAX), None);
+                for n in 3..=10 {
+                    let n = Bounded::new(n).unwrap();
+                    assert_eq!(Bounded::from_index(n.to_index()), Some(n));
+                }
+            }
+
+            #[test]
+            fn from_ascii() {
+                extern crate std;
+                use crate::ParseErrorKind;
+                use std::format;
+
+                type Bounded = super::Bounded<3, 100>;
+                let kind = |bytes| Bounded::from_ascii(bytes).unwrap_err().kind();
+                assert_eq!(Bounded::from_ascii(b"3").unwrap(), Bounded::MIN);
+                assert_eq!(Bounded::from_ascii(b"+042").unwrap(), 42);
+                assert_eq!(Bounded::from_ascii(b"100").unwrap(), Bounded::MAX);
+                assert_eq!(kind(b""), ParseErrorKind::Empty);
+                assert_eq!(kind(b"4x"), ParseErrorKind::InvalidDigit);
+                assert_eq!(kind(b"\xe2\x80\x94"), ParseErrorKind::InvalidDigit);
+                assert_eq!(kind(b"2"), ParseErrorKind::BelowMin);
+                assert_eq!(kind(b"-0"), ParseErrorKind::BelowMin);
+                assert_eq!(kind(b"101"), ParseErrorKind::AboveMax);
+
+                type Full = super::Bounded<{ Inner::MIN }, { Inner::MAX }>;
+                for n in [Inner::MIN, Inner::MAX] {
+                    let bytes = format!("{}", n);
+                    assert_eq!(Full::from_ascii(bytes.as_bytes()).unwrap(), n);
+                }
+                $($(if $signed)?
+                    type Signed = super::Bounded<-5, 5>;
+                    assert_eq!(Signed::from_ascii(b"-5").unwrap(), Signed::MIN);
+                    assert_eq!(Signed::from_ascii(b"-0").unwrap(), 0);
+                    let kind = Signed::from_ascii(b"-6").unwrap_err().kind();
+                    assert_eq!(kind, ParseErrorKind::BelowMin);
+                )?
+            }
+
+            #[test]
+            fn try_collect_into_array() {
+                type Bounded = super::Bounded<3, 10>;
+                let array = Bounded::try_collect_into_array::<3>([3, 7, 10]).unwrap();
+                assert_eq!(array, [3, 7, 10].map(|n| Bounded::new(n).unwrap()));
+                let array = Bounded::try_collect_into_array::<4>((3..7).map(|n| n as Inner));
+                assert_eq!(array.unwrap()[3], 6);
+                assert_eq!(Bounded::try_collect_into_array::<0>([]), Some([]));
+                assert_eq!(Bounded::try_collect_into_array::<3>([3, 11, 10]), None);
+                assert_eq!(Bounded::try_collect_into_array::<3>([3, 4]), None);
+                assert_eq!(Bounded::try_collect_into_array::<3>([3, 4, 5, 6]), None);
+            }
+
+            #[test]
+            fn size_bits() {
+                assert_eq!(super::Bounded::<0, 7>::SIZE_BITS, 3);
+                assert_eq!(super::Bounded::<0, 8>::SIZE_BITS, 4);
+                assert_eq!(super::Bounded::<10, 11>::SIZE_BITS, 1);
+                assert_eq!(super::Bounded::<5, 5>::SIZE_BITS, 0);
+                type Full = super::Bounded<{ Inner::MIN }, { Inner::MAX }>;
+                assert_eq!(Full::SIZE_BITS, Inner::BITS);
+                $($(if $signed)?
+                    assert_eq!(super::Bounded::<-4, 3>::SIZE_BITS, 3);
+                )?
+            }
+
+            #[test]
+            fn repr_bits() {
+                assert_eq!(super::Bounded::<0, 7>::REPR_BITS, Inner::BITS);
+                assert_eq!(super::Bounded::<5, 5>::REPR_BITS, Inner::BITS);
+                let bytes = core::mem::size_of::<Inner>();
+                assert_eq!(super::Bounded::<0, 7>::REPR_BITS as usize, 8 * bytes);
+            }
+
+            #[test]
+            fn bit_packing() {
+                type Low = super::Bounded<3, 10>;
+                type High = super::Bounded<0, 100>;
+                let low = Low::new(7).unwrap();
+                let high = High::new(99).unwrap();
+                let word = high.pack_into(low.pack_into(0, 0), Low::SIZE_BITS);
+                assert_eq!(word, 4 | 99 << 3);
+                assert_eq!(Low::unpack_from(word, 0), Some(low));
+                assert_eq!(High::unpack_from(word, Low::SIZE_BITS), Some(high));
+
+                let word = Low::MIN.pack_into(u64::MAX, 60);
+                assert_eq!(word, !(0b111 << 60));
+                assert_eq!(Low::unpack_from(word, 60), Some(Low::MIN));
+                assert_eq!(High::unpack_from(u64::MAX, 57), None);
+                assert_eq!(super::Bounded::<5, 5>::unpack_from(0, 64).unwrap(), 5);
+            }
+
+            #[test]
+            fn try_into_subrange() {
+                let n = super::Bounded::<0, 100>::new(42).unwrap();
+                let narrow: super::Bounded<40, 50> = n.try_into_subrange().unwrap();
+                assert_eq!(narrow.get(), 42);
+                assert_eq!(n.try_into_subrange::<42, 42>().unwrap(), 42);
+                assert_eq!(n.try_into_subrange::<20, 120>().unwrap(), 42);
+                assert_eq!(n.try_into_subrange::<0, 41>(), None);
+                assert_eq!(n.try_into_subrange::<43, 100>(), None);
+            }
+
+            #[test]
+            fn cross_range_cmp() {
+                let a = super::Bounded::<0, 10>::new(5).unwrap();
+                let b = super::Bounded::<5, 15>::new(5).unwrap();
+                let c = super::Bounded::<5, 15>::new(7).unwrap();
+                assert_eq!(a, b);
+                assert_ne!(a, c);
+                assert!(a < c);
+                assert!(c > a);
+                assert_eq!(a.partial_cmp(&b), Some(core::cmp::Ordering::Equal));
+            }
+
+            #[test]
+            fn borrow_lookup() {
+                extern crate std;
+                use std::collections::HashMap;
+
+                type Bounded = super::Bounded<3, 10>;
+                let mut map = HashMap::new();
+                map.insert(Bounded::new(5).unwrap(), "five");
+                assert_eq!(map.get(&5), Some(&"five"));
+                assert_eq!(map.get(&6), None);
+            }
+
             #[test]
             fn iter() {
                 type Bounded = super::Bounded<{ 0 $($(if $signed)? - 8)? }, 8>;
@@ -748,16 +2431,176 @@ macro_rules! define_bounded_integers {
 }
 
 define_bounded_integers! {
-    BoundedU8 u8 -> u8 u16 u32 u64 u128 usize i16 i32 i64 i128 isize,
-    BoundedU16 u16 -> u16 u32 u64 u128 usize i32 i64 i128,
-    BoundedU32 u32 -> u32 u64 u128 i64 i128,
-    BoundedU64 u64 -> u64 u128 i128,
-    BoundedU128 u128 -> u128,
-    BoundedUsize usize -> usize,
-    BoundedI8 i8 signed -> i8 i16 i32 i64 i128 isize,
-    BoundedI16 i16 signed -> i16 i32 i64 i128 isize,
-    BoundedI32 i32 signed -> i32 i64 i128,
-    BoundedI64 i64 signed -> i64 i128,
-    BoundedI128 i128 signed -> i128,
-    BoundedIsize isize signed -> isize,
+    BoundedU8 u8 u8 unsigned -> u8 u16 u32 u64 u128 usize i16 i32 i64 i128 isize,
+    BoundedU16 u16 u16 unsigned -> u16 u32 u64 u128 usize i32 i64 i128,
+    BoundedU32 u32 u32 unsigned -> u32 u64 u128 i64 i128,
+    BoundedU64 u64 u64 unsigned -> u64 u128 i128,
+    BoundedU128 u128 u128 unsigned -> u128,
+    BoundedUsize usize usize unsigned -> usize,
+    BoundedI8 i8 u8 signed -> i8 i16 i32 i64 i128 isize,
+    BoundedI16 i16 u16 signed -> i16 i32 i64 i128 isize,
+    BoundedI32 i32 u32 signed -> i32 i64 i128,
+    BoundedI64 i64 u64 signed -> i64 i128,
+    BoundedI128 i128 u128 signed -> i128,
+    BoundedIsize isize usize signed -> isize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn as_primitive() {
+        assert_eq!(BoundedU8::<3, 10>::MAX.as_u8(), 10_u8);
+        assert_eq!(BoundedU8::<3, 10>::MAX.as_i16(), 10_i16);
+        assert_eq!(BoundedU16::<3, 10>::MIN.as_usize(), 3_usize);
+        assert_eq!(BoundedI16::<-5, 5>::MIN.as_i64(), -5_i64);
+        assert_eq!(BoundedI16::<-5, 5>::MIN.as_isize(), -5_isize);
+    }
+
+    #[test]
+    fn saturating_cast_to() {
+        use crate::BoundedInteger;
+
+        let n = BoundedU16::<0, 1000>::new(50).unwrap();
+        assert_eq!(n.saturating_cast_to::<BoundedU8<0, 100>>(), 50);
+        assert_eq!(n.saturating_cast_to::<BoundedU8<60, 100>>(), 60);
+        let n = BoundedU16::<0, 1000>::new(500).unwrap();
+        assert_eq!(n.saturating_cast_to::<BoundedU8<0, 100>>(), 100);
+        assert_eq!(n.saturating_cast_to::<BoundedI16<-10, 10>>(), 10);
+
+        let n = BoundedI8::<-8, 7>::new(-5).unwrap();
+        assert_eq!(n.saturating_cast_to::<BoundedU8<3, 10>>(), 3);
+        assert_eq!(n.saturating_cast_to::<BoundedI64<-100, 100>>(), -5);
+    }
+
+    #[test]
+    fn signed_diff_bounded() {
+        let a = BoundedU8::<10, 200>::new(15).unwrap();
+        let b = BoundedU8::<10, 200>::new(200).unwrap();
+        let diff: BoundedI16<-190, 190> = a.signed_diff_bounded(b);
+        assert_eq!(diff, -185);
+        assert_eq!(b.signed_diff_bounded::<-190, 190>(a), 185);
+        assert_eq!(a.signed_diff_bounded::<-190, 190>(a), 0);
+
+        type Full = BoundedI8<{ i8::MIN }, { i8::MAX }>;
+        let diff: BoundedI16<-255, 255> = Full::MIN.signed_diff_bounded(Full::MAX);
+        assert_eq!(diff, -255);
+
+        type Wide = BoundedU64<0, { u64::MAX }>;
+        let diff: BoundedI128<{ -(u64::MAX as i128) }, { u64::MAX as i128 }> =
+            Wide::MAX.signed_diff_bounded(Wide::MIN);
+        assert_eq!(diff, i128::from(u64::MAX));
+    }
+
+    #[test]
+    fn remap_to() {
+        use crate::BoundedInteger;
+
+        type Reading = BoundedU16<0, 1023>;
+        type Percent = BoundedU8<0, 100>;
+        let r = |n| Reading::new(n).unwrap();
+        assert_eq!(Reading::MIN.remap_to::<Percent>(), 0);
+        assert_eq!(Reading::MAX.remap_to::<Percent>(), 100);
+        // 511 maps to 49.95 and 512 to 50.05, which both round to 50.
+        assert_eq!(r(511).remap_to::<Percent>(), 50);
+        assert_eq!(r(512).remap_to::<Percent>(), 50);
+        assert_eq!(r(5).remap_to::<Percent>(), 0);
+        // 5.5 rounds up.
+        assert_eq!(
+            BoundedU8::<0, 10>::new(5)
+                .unwrap()
+                .remap_to::<BoundedU8<0, 11>>(),
+            6
+        );
+
+        let n = BoundedI8::<-10, 10>::new(0).unwrap();
+        assert_eq!(n.remap_to::<BoundedI16<-400, -396>>(), -398);
+        assert_eq!(n.remap_to::<BoundedU8<0, 4>>(), 2);
+
+        type Full = BoundedI128<{ i128::MIN }, { i128::MAX }>;
+        assert_eq!(Full::MIN.remap_to::<BoundedU128<0, { u128::MAX }>>(), 0);
+        assert_eq!(
+            Full::MAX.remap_to::<BoundedU128<0, { u128::MAX }>>(),
+            u128::MAX
+        );
+        assert_eq!(Full::new(0).unwrap().remap_to::<BoundedU8<0, 255>>(), 128);
+        assert_eq!(Full::new(-1).unwrap().remap_to::<BoundedU8<0, 255>>(), 127);
+        assert_eq!(Full::MAX.remap_to::<Full>(), Full::MAX);
+        assert_eq!(Full::new(12345).unwrap().remap_to::<Full>(), 12345);
+
+        assert_eq!(BoundedU8::<7, 7>::MIN.remap_to::<Percent>(), 0);
+    }
+
+    #[test]
+    #[should_panic = "Attempted to subtract out of range"]
+    fn sub_out_of_range() {
+        let _ = BoundedU8::<3, 10>::MIN - 1;
+    }
+
+    #[test]
+    fn checked_chain() {
+        use crate::Checked;
+
+        let n = Checked::new(BoundedI8::<-10, 10>::new(4).unwrap());
+        let in_range = (n * 2 - 14) / 3 % 3;
+        assert_eq!(in_range.into_inner().map(BoundedI8::get), Some(-2));
+
+        let mut out_of_range = n * 3;
+        assert!(out_of_range.is_poisoned());
+        out_of_range -= 5;
+        assert_eq!(out_of_range.into_inner(), None);
+        assert_eq!((n / 0).into_inner(), None);
+    }
+
+    #[test]
+    fn const_range_bounds() {
+        use core::ops::{Range, RangeInclusive};
+
+        const CLOSED: RangeInclusive<i32> = -10..=10;
+        type Closed = BoundedI32<{ *CLOSED.start() }, { *CLOSED.end() }>;
+        assert_eq!(Closed::MIN, -10);
+        assert_eq!(Closed::MAX, 10);
+        assert!(Closed::new(11).is_none());
+
+        const HALF_OPEN: Range<u8> = 0..100;
+        type HalfOpen = BoundedU8<{ HALF_OPEN.start }, { HALF_OPEN.end - 1 }>;
+        assert_eq!(HalfOpen::MAX, 99);
+        assert!(HalfOpen::new(100).is_none());
+    }
+
+    #[test]
+    fn checked_mul_div_wide() {
+        // The products overflow the primitive but the quotients do not.
+        const SCALED: Option<BoundedU32<0, { u32::MAX }>> =
+            BoundedU32::new_const::<{ u32::MAX }>().checked_mul_div(u32::MAX, u32::MAX);
+        assert_eq!(SCALED, Some(BoundedU32::MAX));
+        let n = BoundedI64::<{ i64::MIN }, 0>::MIN;
+        assert_eq!(n.checked_mul_div(i64::MAX, i64::MAX), Some(n));
+        assert_eq!(n.checked_mul_div(i64::MAX, -i64::MAX), None);
+        assert_eq!(n.checked_mul_div(-1, 1), None);
+        assert_eq!(n.checked_mul_div(-1, -1), Some(n));
+
+        let n = BoundedU128::<0, 100>::new(50).unwrap();
+        assert_eq!(n.checked_mul_div(2, 4).map(BoundedU128::get), Some(25));
+        assert_eq!(n.checked_mul_div(u128::MAX, u128::MAX), None);
+    }
+
+    #[test]
+    fn widening_mul() {
+        const PRODUCT: u16 = BoundedU8::<0, { u8::MAX }>::MAX.widening_mul(BoundedU8::MAX);
+        assert_eq!(PRODUCT, u16::from(u8::MAX) * u16::from(u8::MAX));
+
+        let n = BoundedI32::<{ i32::MIN }, { i32::MAX }>::MIN;
+        assert_eq!(n.widening_mul(n), i64::from(i32::MIN) * i64::from(i32::MIN));
+        assert_eq!(n.widening_mul(BoundedI32::MAX), -(1 << 62) + (1 << 31));
+
+        let n = BoundedU64::<0, { u64::MAX }>::MAX;
+        assert_eq!(
+            n.widening_mul(n),
+            u128::from(u64::MAX) * u128::from(u64::MAX)
+        );
+        let n = BoundedUsize::<3, 10>::new(7).unwrap();
+        assert_eq!(n.widening_mul(BoundedUsize::MAX), 70_u128);
+    }
 }
@@ -36,3 +36,45 @@ mod primitives {
     #[repr(u8)]
     pub enum EnumUnsigned { 253..255 }
 }
+::bounded_integer::bounded_integer! {
+    pub struct StructZeroSized { 3..=3 }
+}
+::bounded_integer::bounded_integer! {
+    pub enum EnumZeroSized { -3..=-3 }
+}
+::bounded_integer::bounded_integer! {
+    #[no_copy]
+    pub struct StructNoCopy { 0..1000 }
+}
+::bounded_integer::bounded_integer! {
+    #[no_copy]
+    pub enum EnumNoCopy { -5..5 }
+}
+::bounded_integer::bounded_integer! {
+    #[no_copy]
+    pub enum EnumTwoValues { 5..=6 }
+}
+::bounded_integer::bounded_integer! {
+    #[value_consts]
+    pub struct StructValueConsts { -2..=2 }
+}
+::bounded_integer::bounded_integer! {
+    #[display(format = "{}%")]
+    pub struct StructDisplay { 0..=100 }
+}
+::bounded_integer::bounded_integer! {
+    #[value_consts]
+    pub enum EnumValueConsts { -2..=2 }
+}
+::bounded_integer::bounded_integer! {
+    pub enum EnumLarge { -100..200 }
+}
+::bounded_integer::bounded_integer! {
+    #[repr(offset)]
+    pub struct StructOffset { -300..-100 }
+}
+const RANGE: ::core::ops::RangeInclusive<::core::primitive::i16> = -1000..=1000;
+::bounded_integer::bounded_integer! {
+    #[repr(i16)]
+    pub struct StructConstRange { RANGE }
+}